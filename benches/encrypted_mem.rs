@@ -0,0 +1,87 @@
+//! Benchmarks for the operations of `EncryptedMem` at the secret sizes commonly held in memory.
+//! Every `encrypt`, `decrypt` and `sign` derives the key encryption key by hashing the whole vault,
+//! the `kek_derivation` group measures that hashing on its own so its share of each operation is visible.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use memsecurity::{
+    blake3, EncryptedMem, ZeroizeBytes, DEFAULT_VAULT_PAGES, DEFAULT_VAULT_PAGE_SIZE,
+};
+
+const SIZES: [usize; 4] = [16, 32, 64, 1024];
+
+fn kek_derivation(c: &mut Criterion) {
+    let vault = vec![0u8; DEFAULT_VAULT_PAGES * DEFAULT_VAULT_PAGE_SIZE];
+
+    let mut group = c.benchmark_group("kek_derivation");
+    group.throughput(Throughput::Bytes(vault.len() as u64));
+    group.bench_function("vault_hash", |b| b.iter(|| blake3::hash(black_box(&vault))));
+    group.finish();
+}
+
+fn encrypt(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encrypt");
+
+    for size in SIZES {
+        let plaintext = ZeroizeBytes::new_with_data(&vec![0xAAu8; size]);
+        let mut encrypted_mem = EncryptedMem::new();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &plaintext,
+            |b, plaintext| {
+                b.iter(|| {
+                    encrypted_mem.encrypt(black_box(plaintext)).unwrap();
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn decrypt(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decrypt");
+
+    for size in SIZES {
+        let plaintext = ZeroizeBytes::new_with_data(&vec![0xAAu8; size]);
+        let mut encrypted_mem = EncryptedMem::new();
+        encrypted_mem.encrypt(&plaintext).unwrap();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &encrypted_mem,
+            |b, encrypted_mem| b.iter(|| black_box(encrypted_mem).decrypt().unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+fn sign(c: &mut Criterion) {
+    let mut encrypted_mem = EncryptedMem::new();
+    encrypted_mem.generate_ed25519().unwrap();
+
+    let mut group = c.benchmark_group("sign");
+
+    for size in SIZES {
+        let message = vec![0xAAu8; size];
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &message, |b, message| {
+            b.iter_batched(
+                || ZeroizeBytes::new_with_data(message),
+                |message| encrypted_mem.sign(message).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, kek_derivation, encrypt, decrypt, sign);
+criterion_main!(benches);
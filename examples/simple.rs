@@ -3,6 +3,16 @@ fn main() {
     foo()
 }
 
+#[cfg(not(all(
+    feature = "encryption",
+    feature = "ed25519",
+    feature = "x25519",
+    feature = "uuid"
+)))]
+fn foo() {
+    println!("Run this example with `--features full`");
+}
+
 #[cfg(all(
     feature = "encryption",
     feature = "ed25519",
@@ -33,18 +43,29 @@ fn foo() {
         assert!(foo.sign(CsprngArray::<32>::gen()).is_err());
     }
 
+    {
+        use ed25519_dalek::Verifier;
+
+        let mut signer = EncryptedMem::new();
+        let verifying_key = signer.generate_ed25519().unwrap();
+
+        let message = ZeroizeBytes::new_with_data(b"message");
+        let signature = signer
+            .sign(ZeroizeBytes::new_with_data(b"message"))
+            .unwrap();
+
+        assert!(verifying_key
+            .verify(message.expose_borrowed(), &signature)
+            .is_ok());
+    }
+
     {
         let mut alice_kek = EncryptedMem::new();
-        let alice_secret = CsprngArray::<32>::gen();
-        alice_kek.encrypt(&alice_secret).unwrap();
-        drop(alice_secret);
-        let alice_public = alice_kek.x25519_public_key().unwrap();
+        let alice_public = alice_kek.generate_x25519().unwrap();
+        assert_eq!(alice_kek.x25519_public_key().unwrap(), alice_public);
 
         let mut bob_kek = EncryptedMem::new();
-        let bob_secret = CsprngArray::<32>::gen();
-        bob_kek.encrypt(&bob_secret).unwrap();
-        drop(bob_secret);
-        let bob_public = bob_kek.x25519_public_key().unwrap();
+        let bob_public = bob_kek.generate_x25519().unwrap();
 
         let alice_shared_secret = alice_kek.x25519_dh(bob_public).unwrap();
         let bob_shared_secret = bob_kek.x25519_dh(alice_public).unwrap();
@@ -62,6 +83,21 @@ fn foo() {
         assert_eq!(random.expose_borrowed(), deser_random.expose_borrowed())
     }
 
+    {
+        use std::io::{Read, Write};
+
+        let mut sealed_frames = Vec::new();
+        let mut writer = SealedWriter::new(&mut sealed_frames);
+        writer.write_all(b"stream of secrets").unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = SealedReader::new(sealed_frames.as_slice());
+        let mut plaintext = Vec::new();
+        reader.read_to_end(&mut plaintext).unwrap();
+
+        assert_eq!(plaintext.as_slice(), b"stream of secrets");
+    }
+
     {
         let mut store = EncryptedMem::new();
         store.encrypt_uuid().unwrap();
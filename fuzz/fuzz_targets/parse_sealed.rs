@@ -0,0 +1,28 @@
+//! Feeds arbitrary bytes to the parsers of sealed values. They must never panic, malformed input must be
+//! rejected with a [MemSecurityErr] and input that happens to be well framed must fail to authenticate
+//! or be reported as too short to hold a tag.
+//! Run with `cargo +nightly fuzz run parse_sealed` from the root of the repository.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use memsecurity::{EncryptedMem, MemSecurityErr};
+
+fn assert_rejected(sealed: EncryptedMem) {
+    assert!(matches!(
+        sealed.decrypt(),
+        Err(MemSecurityErr::AuthenticationFailed | MemSecurityErr::CiphertextTooShort { .. })
+    ));
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(sealed) = EncryptedMem::from_bytes(data) {
+        assert_rejected(sealed);
+    }
+
+    if let Ok(envelope) = core::str::from_utf8(data) {
+        if let Ok(sealed) = EncryptedMem::from_envelope(envelope) {
+            assert_rejected(sealed);
+        }
+    }
+});
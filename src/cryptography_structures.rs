@@ -1,31 +1,169 @@
-use crate::{ToBlake3Hash, ZeroizeArray};
-
-/// The 32 bytes of a Blake3 Hash
-pub type Blake3Hash = ZeroizeArray<32>;
-/// The 64 bytes of an ed25519 keypair
-pub type Ed25519KeyPair = ZeroizeArray<64>;
-/// The 32 bytes of an ed25519 keypair
-pub type Ed25519PublicKey = ZeroizeArray<32>;
-/// The 32 bytes of an ed25519 secret
-pub type Ed25519SecretKey = ZeroizeArray<32>;
-/// The 64 bytes of an X25519 keypair
-pub type X25519StaticKeyPair = ZeroizeArray<64>;
-/// The 32 bytes of an X25519 public key
-pub type X25519PublicKey = ZeroizeArray<32>;
-/// The 32 bytes of an X25519 static secret key
-pub type X25519StaticSecretKey = ZeroizeArray<32>;
-/// The 32 bytes of an X25519 ephemeral secret key
-pub type X25519EphemeralSecretKey = ZeroizeArray<32>;
-/// The 32 bytes of an X25519 reusable secret key
-pub type X25519ReusableSecretKey = ZeroizeArray<32>;
-/// The 32 bytes of an X25519 shared secret key from the outcome of a DH key exchange
-pub type X25519SharedSecretKey = ZeroizeArray<32>;
-/// The 32 bytes secret key
-pub type Key32Byte = ZeroizeArray<32>;
-/// The 16 bytes of a Poly1305 AEAD tag
-pub type Poly1305Tag = ZeroizeArray<16>;
-/// The  bytes of a Tai64N timestamp
-pub type TaiTimestamp = ZeroizeArray<12>;
+use crate::{MemSecurityResult, ToBlake3Hash, ZeroizeArray};
+use borsh::{BorshDeserialize, BorshSerialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Define a newtype over [ZeroizeArray] for one cryptographic role so that the compiler rejects
+/// passing a value of one role where another is expected even when both have the same length
+macro_rules! role_newtype {
+    ($(#[$doc:meta])* $name:ident, $len:literal) => {
+        $(#[$doc])*
+        /// #### Structure
+        #[doc = concat!("```rs\npub struct ", stringify!($name), "(ZeroizeArray<", stringify!($len), ">);\n```")]
+        #[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+        #[cfg_attr(feature = "clonable_mem", derive(Clone))]
+        pub struct $name(ZeroizeArray<$len>);
+
+        impl $name {
+            /// Initialize the value from its bytes
+            pub fn new(value: [u8; $len]) -> Self {
+                Self(ZeroizeArray::new(value))
+            }
+
+            /// Initialize the value from a slice returning an error if the length of the slice is not
+            #[doc = concat!("`", stringify!($len), "` bytes")]
+            pub fn new_from_slice(value: &[u8]) -> MemSecurityResult<Self> {
+                Ok(Self(ZeroizeArray::new_from_slice(value)?))
+            }
+
+            /// Take ownership of a [ZeroizeArray] holding bytes of this role
+            pub fn from_array(value: ZeroizeArray<$len>) -> Self {
+                Self(value)
+            }
+
+            /// Expose the internal bytes as a borrowed array
+            pub fn expose_borrowed(&self) -> &[u8; $len] {
+                self.0.expose_borrowed()
+            }
+
+            /// Borrow the underlying [ZeroizeArray]
+            pub fn as_array(&self) -> &ZeroizeArray<$len> {
+                &self.0
+            }
+
+            /// Unwrap the underlying [ZeroizeArray]
+            pub fn into_array(self) -> ZeroizeArray<$len> {
+                self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl ToBlake3Hash for $name {
+            fn hash(&self) -> blake3::Hash {
+                self.0.hash()
+            }
+        }
+
+        impl Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize()
+            }
+        }
+
+        impl ZeroizeOnDrop for $name {}
+    };
+}
+
+role_newtype!(
+    /// The 32 bytes of a Blake3 Hash
+    Blake3Hash,
+    32
+);
+role_newtype!(
+    /// The 64 bytes of an ed25519 keypair
+    Ed25519KeyPair,
+    64
+);
+role_newtype!(
+    /// The 32 bytes of an ed25519 public key
+    Ed25519PublicKey,
+    32
+);
+role_newtype!(
+    /// The 32 bytes of an ed25519 secret
+    Ed25519SecretKey,
+    32
+);
+role_newtype!(
+    /// The 64 bytes of an X25519 keypair
+    X25519StaticKeyPair,
+    64
+);
+role_newtype!(
+    /// The 32 bytes of an X25519 public key
+    X25519PublicKey,
+    32
+);
+role_newtype!(
+    /// The 32 bytes of an X25519 static secret key
+    X25519StaticSecretKey,
+    32
+);
+role_newtype!(
+    /// The 32 bytes of an X25519 ephemeral secret key
+    X25519EphemeralSecretKey,
+    32
+);
+role_newtype!(
+    /// The 32 bytes of an X25519 reusable secret key
+    X25519ReusableSecretKey,
+    32
+);
+role_newtype!(
+    /// The 32 bytes of an X25519 shared secret key from the outcome of a DH key exchange
+    X25519SharedSecretKey,
+    32
+);
+role_newtype!(
+    /// The 32 bytes secret key
+    Key32Byte,
+    32
+);
+role_newtype!(
+    /// The 16 bytes of a Poly1305 AEAD tag
+    Poly1305Tag,
+    16
+);
+role_newtype!(
+    /// The 12 bytes of a Tai64N timestamp
+    TaiTimestamp,
+    12
+);
+
+impl Blake3Hash {
+    /// Hold the bytes of a [blake3::Hash]
+    pub fn from_hash(hash: &blake3::Hash) -> Self {
+        Self::new(*hash.as_bytes())
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Ed25519PublicKey {
+    /// Hold the bytes of an [ed25519_dalek::VerifyingKey]
+    pub fn from_verifying_key(verifying_key: &ed25519_dalek::VerifyingKey) -> Self {
+        Self::new(verifying_key.to_bytes())
+    }
+}
+
+#[cfg(feature = "x25519")]
+impl X25519PublicKey {
+    /// Hold the bytes of an [x25519_dalek::PublicKey]
+    pub fn from_public_key(public_key: &x25519_dalek::PublicKey) -> Self {
+        Self::new(public_key.to_bytes())
+    }
+}
+
+#[cfg(feature = "x25519")]
+impl X25519SharedSecretKey {
+    /// Hold the bytes of an [x25519_dalek::SharedSecret] which wipes itself when it is dropped
+    pub fn from_shared_secret(shared_secret: x25519_dalek::SharedSecret) -> Self {
+        Self::new(shared_secret.to_bytes())
+    }
+}
 
 impl ToBlake3Hash for ZeroizeArray<8> {
     fn hash(&self) -> blake3::Hash {
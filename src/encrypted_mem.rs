@@ -1,12 +1,13 @@
 //! This module contains types and methods used to create a sealing key that stretches across multiple
 //! memory pages ensuring impossible key recovery if certain attacks are used to try and recover the key.
 //! These attacks are specified in the crate documentation.
-///
 
 #[cfg(all(feature = "symm_asymm", feature = "random"))]
-use crate::{CsprngArray, ZeroizeBytes};
+use crate::{CsprngArray, ZeroizeArray, ZeroizeBytes};
+use crate::{MemSecurityErr, MemSecurityResult};
 use ascon_aead::Ascon128a;
 use core::fmt;
+use once_cell::sync::OnceCell;
 
 type AsconNonce = ascon_aead::Nonce<Ascon128a>;
 
@@ -16,11 +17,32 @@ pub const SECRET_KEY_16BYTE: usize = 16;
 pub const SECRET_KEY_32BYTE: usize = 32;
 /// The nonce length of Ascon 128 cipher
 pub const ASCON128_NONCE_LEN: usize = 16;
+/// The length of the Ascon128a authentication tag appended to every ciphertext
+pub const ASCON128_TAG_LEN: usize = 16;
+/// The domain label prepended to the caller's context by [EncryptedMem::sign_prehashed], so signatures over a
+/// `blake3` prehash can never be confused with Ed25519ph signatures over a SHA-512 hash of the message itself
+#[cfg(feature = "ed25519")]
+pub const BLAKE3_PREHASH_SIGNATURE_LABEL: &[u8] = b"memsecurity-blake3-prehash-v1:";
+/// The length of the random prefix of a counter based nonce, the remaining bytes hold a `u64` counter
+pub const COUNTER_NONCE_PREFIX_LEN: usize = ASCON128_NONCE_LEN - core::mem::size_of::<u64>();
 
 /// The number of pages used to accommodate one page of 4KiB in size.
 pub const DEFAULT_VAULT_PAGES: usize = 4;
 /// A size in KiB of one page (a page is a fixed-size block of memory used by the operating system to manage memory)
 pub const DEFAULT_VAULT_PAGE_SIZE: usize = 4096_usize;
+/// The number of pages of the sealing key owned by a [ScopedVault], enough to hold [MIN_VAULT_ENTROPY_BYTES]
+pub const SCOPED_VAULT_PAGES: usize = MIN_VAULT_ENTROPY_BYTES.div_ceil(DEFAULT_VAULT_PAGE_SIZE);
+/// The minimum number of random bytes the vault pages must hold so that the sealing key is spread across enough memory
+pub const MIN_VAULT_ENTROPY_BYTES: usize = 4096;
+/// The minimum length of a seed passed to [init_sealing_key_from_seed]
+pub const MIN_SEALING_KEY_SEED_LEN: usize = 32;
+/// The version byte written by [EncryptedMem::to_bytes] for values sealed with Ascon128a
+pub const ENCRYPTED_MEM_BYTES_VERSION: u8 = 1;
+/// The length of the `version || nonce || ciphertext length` header written by [EncryptedMem::to_bytes]
+const ENCRYPTED_MEM_BYTES_HEADER_LEN: usize = 1 + ASCON128_NONCE_LEN + core::mem::size_of::<u64>();
+/// The version byte written by [EncryptedMem::to_envelope] for envelopes sealed with Ascon128a
+#[cfg(feature = "envelope")]
+pub const ENVELOPE_VERSION: u8 = 1;
 /// The layout of the bytes used to create the key
 pub type VaultPagesLayout<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> =
     [[u8; VAULT_PAGE_SIZE]; VAULT_PAGES];
@@ -30,14 +52,26 @@ pub type VaultPagesLayout<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize
 /// ```rs
 /// pub struct EncryptedMem {
 ///     ciphertext: ZeroizeBytes,
-///     nonce: AsconNonce,
+///     nonce: ZeroizeArray<ASCON128_NONCE_LEN>,
+///     nonce_used: bool,
+///     counter_nonce: bool,
+///     aad: Vec<u8>,
+///     fingerprint: OnceCell<blake3::Hash>,
+///     context: Option<String>,
 /// }
 /// ```
-
 pub struct EncryptedMem {
     ciphertext: ZeroizeBytes,
     #[cfg(feature = "encryption")]
-    nonce: AsconNonce,
+    nonce: ZeroizeArray<ASCON128_NONCE_LEN>,
+    #[cfg(feature = "encryption")]
+    nonce_used: bool,
+    #[cfg(feature = "encryption")]
+    counter_nonce: bool,
+    #[cfg(feature = "encryption")]
+    aad: Vec<u8>,
+    fingerprint: OnceCell<blake3::Hash>,
+    context: Option<String>,
 }
 
 impl EncryptedMem {
@@ -47,14 +81,205 @@ impl EncryptedMem {
     /// let data = EncryptedMem::new();
     /// ```
     pub fn new() -> Self {
+        EncryptedMem {
+            ciphertext: ZeroizeBytes::new(),
+            #[cfg(feature = "encryption")]
+            nonce: Self::random_nonce(),
+            #[cfg(feature = "encryption")]
+            nonce_used: false,
+            #[cfg(feature = "encryption")]
+            counter_nonce: false,
+            #[cfg(feature = "encryption")]
+            aad: Vec::new(),
+            fingerprint: OnceCell::new(),
+            context: None,
+        }
+    }
+
+    /// Initializes a new [EncryptedMem] whose key encryption key is derived from the vault under `context`,
+    /// for example a tenant identifier. A ciphertext sealed under one context cannot be decrypted under
+    /// another context or by an [EncryptedMem] without a context, which separates tenants sharing one process.
+    /// #### Usage
+    /// ```rs
+    /// let tenant_a = EncryptedMem::with_context("tenant-a");
+    /// ```
+    pub fn with_context(context: &str) -> Self {
+        EncryptedMem {
+            context: Some(context.to_owned()),
+            ..Self::new()
+        }
+    }
+
+    /// Generate a fresh random nonce
+    fn random_nonce() -> ZeroizeArray<ASCON128_NONCE_LEN> {
         let nonce = CsprngArray::<ASCON128_NONCE_LEN>::gen();
 
         assert_ne!(nonce.expose(), [0u8; ASCON128_NONCE_LEN]);
 
+        ZeroizeArray::new(nonce.expose())
+    }
+
+    /// Initializes a new [EncryptedMem] whose nonce is derived deterministically from a
+    /// random `prefix` and a monotonic `counter`. The nonce is laid out as `prefix || counter`
+    /// with the counter encoded in big-endian.
+    ///
+    /// This guarantees unique nonces for high-volume sealing as long as the caller never
+    /// reuses a `(prefix, counter)` pair. Random 128-bit nonces from [EncryptedMem::new]
+    /// only approach the birthday bound after around 2^64 encryptions so they still offer
+    /// huge headroom, this is meant for callers that already track a counter.
+    ///
+    /// The counter nonce is used for the first encryption only. Encrypting into the same [EncryptedMem] again,
+    /// including through [EncryptedMem::refresh_nonce], returns [MemSecurityErr::CounterNonceReused] instead of
+    /// silently switching to a random nonce, so create a new [EncryptedMem] with the next counter instead.
+    /// #### Usage
+    /// ```rs
+    /// let prefix = [7u8; COUNTER_NONCE_PREFIX_LEN];
+    /// let data = EncryptedMem::new_counter_nonce(prefix, 1);
+    /// ```
+    pub fn new_counter_nonce(prefix: [u8; COUNTER_NONCE_PREFIX_LEN], counter: u64) -> Self {
+        let mut nonce = [0u8; ASCON128_NONCE_LEN];
+        nonce[..COUNTER_NONCE_PREFIX_LEN].copy_from_slice(&prefix);
+        nonce[COUNTER_NONCE_PREFIX_LEN..].copy_from_slice(&counter.to_be_bytes());
+
         EncryptedMem {
             ciphertext: ZeroizeBytes::new(),
             #[cfg(feature = "encryption")]
-            nonce: *AsconNonce::from_slice(nonce.expose().as_ref()),
+            nonce: ZeroizeArray::new(nonce),
+            #[cfg(feature = "encryption")]
+            nonce_used: false,
+            #[cfg(feature = "encryption")]
+            counter_nonce: true,
+            #[cfg(feature = "encryption")]
+            aad: Vec::new(),
+            fingerprint: OnceCell::new(),
+            context: None,
+        }
+    }
+
+    /// Rebuild an [EncryptedMem] from a nonce and a ciphertext produced by a previous encryption
+    pub(crate) fn from_parts(nonce: &[u8], ciphertext: ZeroizeBytes) -> MemSecurityResult<Self> {
+        Ok(EncryptedMem {
+            ciphertext,
+            #[cfg(feature = "encryption")]
+            nonce: ZeroizeArray::new_from_slice(nonce)?,
+            #[cfg(feature = "encryption")]
+            nonce_used: true,
+            #[cfg(feature = "encryption")]
+            counter_nonce: false,
+            #[cfg(feature = "encryption")]
+            aad: Vec::new(),
+            fingerprint: OnceCell::new(),
+            context: None,
+        })
+    }
+
+    /// Bind `aad` to a value rebuilt with [EncryptedMem::from_parts]
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_aad(mut self, aad: &[u8]) -> Self {
+        self.aad = aad.to_vec();
+
+        self
+    }
+
+    /// Serialize the ciphertext and nonce into a compact zeroizing buffer, for example to pass a sealed value
+    /// over a channel within this process. The layout is
+    /// `version || nonce || ciphertext length (u64 little-endian) || ciphertext` where the version is
+    /// [ENCRYPTED_MEM_BYTES_VERSION]. The context and associated data are not included.
+    #[cfg(feature = "encryption")]
+    pub fn to_bytes(&self) -> ZeroizeBytes {
+        let ciphertext = self.ciphertext.expose_borrowed();
+
+        let mut outcome =
+            ZeroizeBytes::new_with_capacity(ENCRYPTED_MEM_BYTES_HEADER_LEN + ciphertext.len());
+        outcome.set_byte(ENCRYPTED_MEM_BYTES_VERSION);
+        outcome.extend_from_slice(self.nonce.expose_borrowed());
+        outcome.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+        outcome.extend_from_slice(ciphertext);
+
+        outcome
+    }
+
+    /// Parse an [EncryptedMem] from the bytes produced by [EncryptedMem::to_bytes].
+    /// Returns [MemSecurityErr::UnsupportedVersion] if the version byte is not [ENCRYPTED_MEM_BYTES_VERSION],
+    /// [MemSecurityErr::CiphertextTooShort] if the framed ciphertext cannot hold a tag and
+    /// [MemSecurityErr::InvalidSliceLength] if the bytes are truncated or have trailing bytes.
+    /// The sealed value can only be decrypted by the process that encrypted it, and only without a context.
+    #[cfg(feature = "encryption")]
+    pub fn from_bytes(bytes: &[u8]) -> MemSecurityResult<Self> {
+        if bytes.len() < ENCRYPTED_MEM_BYTES_HEADER_LEN {
+            return Err(MemSecurityErr::InvalidSliceLength {
+                expected: ENCRYPTED_MEM_BYTES_HEADER_LEN,
+                found: bytes.len(),
+            });
+        }
+
+        let (header, ciphertext) = bytes.split_at(ENCRYPTED_MEM_BYTES_HEADER_LEN);
+        let (version, header) = (header[0], &header[1..]);
+        let (nonce, ciphertext_len) = header.split_at(ASCON128_NONCE_LEN);
+
+        if version != ENCRYPTED_MEM_BYTES_VERSION {
+            return Err(MemSecurityErr::UnsupportedVersion { found: version });
+        }
+
+        let mut len_bytes = [0u8; core::mem::size_of::<u64>()];
+        len_bytes.copy_from_slice(ciphertext_len);
+        let ciphertext_len = usize::try_from(u64::from_le_bytes(len_bytes)).unwrap_or(usize::MAX);
+
+        if ciphertext_len < crate::ASCON128_TAG_LEN {
+            return Err(MemSecurityErr::CiphertextTooShort {
+                min: crate::ASCON128_TAG_LEN,
+                found: ciphertext_len,
+            });
+        }
+
+        if ciphertext.len() != ciphertext_len {
+            return Err(MemSecurityErr::InvalidSliceLength {
+                expected: ciphertext_len.saturating_add(ENCRYPTED_MEM_BYTES_HEADER_LEN),
+                found: bytes.len(),
+            });
+        }
+
+        Self::from_parts(nonce, ZeroizeBytes::new_with_data(ciphertext))
+    }
+
+    /// Encode the nonce and ciphertext as an unpadded base64url string that can be stored in a JSON field
+    /// or a config file. The decoded layout is `version || nonce || ciphertext` where the version is
+    /// [ENVELOPE_VERSION] so that envelopes sealed with a future cipher can be told apart. The context and associated data are not included.
+    #[cfg(feature = "envelope")]
+    pub fn to_envelope(&self) -> String {
+        use base64ct::{Base64UrlUnpadded, Encoding};
+
+        let ciphertext = self.ciphertext.expose_borrowed();
+
+        let mut envelope =
+            ZeroizeBytes::new_with_capacity(1 + ASCON128_NONCE_LEN + ciphertext.len());
+        envelope.set_byte(ENVELOPE_VERSION);
+        envelope.extend_from_slice(self.nonce.expose_borrowed());
+        envelope.extend_from_slice(ciphertext);
+
+        Base64UrlUnpadded::encode_string(envelope.expose_borrowed())
+    }
+
+    /// Parse an [EncryptedMem] from a string produced by [EncryptedMem::to_envelope]. The decoded bytes are wiped
+    /// once they have been parsed. Returns [MemSecurityErr::InvalidEnvelope] if the string is not unpadded base64url,
+    /// the version is unknown or the envelope is too short to hold a nonce and a tag.
+    #[cfg(feature = "envelope")]
+    pub fn from_envelope(envelope: &str) -> MemSecurityResult<Self> {
+        use base64ct::{Base64UrlUnpadded, Encoding};
+
+        let decoded = zeroize::Zeroizing::new(
+            Base64UrlUnpadded::decode_vec(envelope).map_err(|_| MemSecurityErr::InvalidEnvelope)?,
+        );
+
+        match decoded.split_first() {
+            Some((&ENVELOPE_VERSION, sealed))
+                if sealed.len() >= ASCON128_NONCE_LEN + crate::ASCON128_TAG_LEN =>
+            {
+                let (nonce, ciphertext) = sealed.split_at(ASCON128_NONCE_LEN);
+
+                Self::from_parts(nonce, ZeroizeBytes::new_with_data(ciphertext))
+            }
+            _ => Err(MemSecurityErr::InvalidEnvelope),
         }
     }
 
@@ -63,11 +288,90 @@ impl EncryptedMem {
         &self.ciphertext
     }
 
-    /// Expose Nonce
+    /// Returns `true` once a plaintext has been encrypted into this [EncryptedMem], even an empty one,
+    /// since every ciphertext carries an authentication tag. Use this to guard calls to [EncryptedMem::decrypt].
+    pub fn is_sealed(&self) -> bool {
+        !self.ciphertext.expose_borrowed().is_empty()
+    }
+
+    /// Returns `true` if there is no plaintext to decrypt, either because nothing has been encrypted yet
+    /// or because the plaintext that was encrypted was empty. Use [EncryptedMem::is_sealed] to tell the two apart.
+    pub fn is_empty(&self) -> bool {
+        self.ciphertext.expose_borrowed().len() <= crate::ASCON128_TAG_LEN
+    }
+
+    /// The Blake3 hash of the ciphertext, computed the first time it is requested after each encryption
+    /// and cached so that formatting or comparing a sealed value repeatedly does not hash the ciphertext again
+    pub fn fingerprint(&self) -> &blake3::Hash {
+        self.fingerprint
+            .get_or_init(|| blake3::hash(self.ciphertext.expose_borrowed()))
+    }
+
+    /// Replace the ciphertext and discard the cached fingerprint of the previous one
+    #[cfg(feature = "encryption")]
+    fn set_ciphertext(&mut self, ciphertext: &[u8]) {
+        self.ciphertext = ZeroizeBytes::new_with_data(ciphertext);
+        self.fingerprint = OnceCell::new();
+    }
+
+    /// Wipe the whole allocation of the ciphertext, including its spare capacity, and release it
+    #[cfg(feature = "encryption")]
+    pub(crate) fn wipe(&mut self) {
+        self.ciphertext.wipe_and_shrink_to_fit(0);
+        self.fingerprint = OnceCell::new();
+    }
+
+    /// The context the key encryption key is derived under, if any
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// The associated data authenticated alongside the ciphertext, empty unless set by [EncryptedMemBuilder::aad]
+    #[cfg(feature = "encryption")]
+    pub fn aad(&self) -> &[u8] {
+        &self.aad
+    }
+
+    /// Expose a borrowed view of the nonce which is zeroed when this [EncryptedMem] is dropped
     #[cfg(feature = "encryption")]
-    pub fn nonce(&self) -> &AsconNonce {
+    pub fn nonce(&self) -> &ZeroizeArray<ASCON128_NONCE_LEN> {
         &self.nonce
     }
+
+    /// The nonce the next encryption must use. The nonce given on construction is used for the first encryption
+    /// and a fresh random nonce for every later one, so that encrypting into the same [EncryptedMem] again never
+    /// reuses a (key, nonce) pair. A counter nonce from [EncryptedMem::new_counter_nonce] is never replaced by a
+    /// random one, [MemSecurityErr::CounterNonceReused] is returned instead.
+    /// Nothing is changed until the encryption succeeds and [EncryptedMem::commit_sealed] is called.
+    #[cfg(feature = "encryption")]
+    fn next_nonce(&self) -> MemSecurityResult<ZeroizeArray<ASCON128_NONCE_LEN>> {
+        match (self.nonce_used, self.counter_nonce) {
+            (false, _) => Ok(ZeroizeArray::new(*self.nonce.expose_borrowed())),
+            (true, false) => Ok(Self::random_nonce()),
+            (true, true) => Err(MemSecurityErr::CounterNonceReused),
+        }
+    }
+
+    /// Store the nonce and the ciphertext of a successful encryption together, so that a failed encryption
+    /// leaves the previous nonce and ciphertext in place and the previous secret can still be decrypted
+    #[cfg(feature = "encryption")]
+    fn commit_sealed(&mut self, nonce: ZeroizeArray<ASCON128_NONCE_LEN>, ciphertext: &[u8]) {
+        self.nonce = nonce;
+        self.nonce_used = true;
+        self.set_ciphertext(ciphertext);
+    }
+
+    /// View the nonce as the type expected by the Ascon128a cipher without copying it
+    #[cfg(feature = "encryption")]
+    fn ascon_nonce(&self) -> &AsconNonce {
+        Self::as_ascon_nonce(&self.nonce)
+    }
+
+    /// View `nonce` as the type expected by the Ascon128a cipher without copying it
+    #[cfg(feature = "encryption")]
+    fn as_ascon_nonce(nonce: &ZeroizeArray<ASCON128_NONCE_LEN>) -> &AsconNonce {
+        AsconNonce::from_slice(nonce.expose_borrowed())
+    }
 }
 
 impl Default for EncryptedMem {
@@ -78,94 +382,929 @@ impl Default for EncryptedMem {
 
 impl fmt::Debug for EncryptedMem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only the first 8 bytes of the cached fingerprint are printed to keep log lines short
+        let fingerprint = self.fingerprint().to_hex();
+
         f.debug_struct("EncryptedMem")
-            .field(
-                "ciphertext",
-                &blake3::hash(self.ciphertext.expose_borrowed()),
-            )
-            .field("nonce", &blake3::hash(self.nonce.as_ref()))
+            .field("ciphertext_len", &self.ciphertext.expose_borrowed().len())
+            .field("nonce_len", &self.nonce.expose_borrowed().len())
+            .field("fingerprint", &format_args!("{}", &fingerprint[..16]))
+            .field("context", &self.context)
             .finish()
     }
 }
 
+/// The AEAD cipher an [EncryptedMem] is sealed with. Ascon128a is the only cipher the sealing key is currently
+/// derived for, the enum is non exhaustive so that other ciphers can be added without a breaking change.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum CipherChoice {
+    /// Ascon128a with a 16 byte nonce and a 16 byte authentication tag
+    #[default]
+    Ascon128a,
+}
+
+impl CipherChoice {
+    /// The length of the nonce the cipher expects
+    pub const fn nonce_len(&self) -> usize {
+        match self {
+            Self::Ascon128a => ASCON128_NONCE_LEN,
+        }
+    }
+}
+
+/// Configures the cipher, nonce, associated data and context of an [EncryptedMem] in one place
+/// instead of a constructor for every combination. The combination is validated by [EncryptedMemBuilder::build].
+/// #### Structure
+/// ```rs
+/// pub struct EncryptedMemBuilder {
+///     cipher: CipherChoice,
+///     aad: Vec<u8>,
+///     nonce: Option<ZeroizeBytes>,
+///     context: Option<String>,
+/// }
+/// ```
+/// #### Usage
+/// ```rs
+/// let mut data = EncryptedMemBuilder::new()
+///     .cipher(CipherChoice::Ascon128a)
+///     .aad(b"record-42")
+///     .context("tenant-a")
+///     .build()?;
+/// ```
+#[derive(Debug, Default)]
+pub struct EncryptedMemBuilder {
+    cipher: CipherChoice,
+    aad: Vec<u8>,
+    nonce: Option<ZeroizeBytes>,
+    context: Option<String>,
+}
+
+impl EncryptedMemBuilder {
+    /// Start from Ascon128a with a random nonce, no associated data and no context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cipher used to seal the secret
+    pub fn cipher(mut self, cipher: CipherChoice) -> Self {
+        self.cipher = cipher;
+
+        self
+    }
+
+    /// Associated data that is authenticated but not encrypted, for example a record identifier.
+    /// Decryption fails unless the [EncryptedMem] carries the same associated data.
+    pub fn aad(mut self, aad: &[u8]) -> Self {
+        self.aad = aad.to_vec();
+
+        self
+    }
+
+    /// The nonce used for the first encryption, a fresh random nonce is used if it is `None`.
+    /// The nonce is replaced with a random one whenever the [EncryptedMem] is encrypted again.
+    pub fn nonce(mut self, nonce: Option<&[u8]>) -> Self {
+        self.nonce = nonce.map(ZeroizeBytes::new_with_data);
+
+        self
+    }
+
+    /// The context the key encryption key is derived under, see [EncryptedMem::with_context]
+    pub fn context(mut self, context: &str) -> Self {
+        self.context = Some(context.to_owned());
+
+        self
+    }
+
+    /// Build the configured [EncryptedMem].
+    /// Returns [MemSecurityErr::InvalidSliceLength] if the nonce does not have the length the cipher expects.
+    pub fn build(self) -> MemSecurityResult<EncryptedMem> {
+        let mut encrypted_mem = EncryptedMem {
+            aad: self.aad,
+            context: self.context,
+            ..EncryptedMem::new()
+        };
+
+        if let Some(nonce) = self.nonce {
+            let nonce = nonce.expose_borrowed();
+
+            if nonce.len() != self.cipher.nonce_len() {
+                return Err(MemSecurityErr::InvalidSliceLength {
+                    expected: self.cipher.nonce_len(),
+                    found: nonce.len(),
+                });
+            }
+
+            encrypted_mem.nonce = ZeroizeArray::new_from_slice(nonce)?;
+        }
+
+        Ok(encrypted_mem)
+    }
+}
+
 /// The struct used to hold the sealing key used for encrypt data
-/// while it's loaded in memory.
+/// while it's loaded in memory. The vault is held as two sets of random pages, the key encryption key
+/// is derived from the XOR of each page with its mask. Refreshing the vault XORs the same fresh random
+/// bytes into a page and its mask so the raw bytes in memory keep moving while the derived key stays the same.
 /// #### Structure
 /// ```rs
 /// pub struct SealingKey<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>(
-///     [[u8; VAULT_PAGE_SIZE]; VAULT_PAGES],
+///     [[u8; VAULT_PAGE_SIZE]; VAULT_PAGES], // pages
+///     [[u8; VAULT_PAGE_SIZE]; VAULT_PAGES], // masks
 /// );
 /// ```
 pub struct SealingKey<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>(
-    [[u8; VAULT_PAGE_SIZE]; VAULT_PAGES],
+    VaultStorage<VAULT_PAGES, VAULT_PAGE_SIZE>,
+    VaultStorage<VAULT_PAGES, VAULT_PAGE_SIZE>,
 );
 
+/// A sealing key owned by one thread instead of the process-wide sealing key, for short-lived worker threads
+/// that seal a few values of their own. Deriving the key encryption key does not contend with other threads
+/// and values sealed by this vault cannot be opened by [EncryptedMem::decrypt] or by any other vault.
+/// The vault pages are `mlock`ed and zeroized when the vault is dropped. It cannot be sent to another thread,
+/// use [ScopedVault::with_thread_vault] to get a vault that lives exactly as long as the current thread.
+/// #### Structure
+/// ```rs
+/// pub struct ScopedVault {
+///     sealing_key: SealingKey<SCOPED_VAULT_PAGES, DEFAULT_VAULT_PAGE_SIZE>,
+///     not_send: PhantomData<*const ()>,
+/// }
+/// ```
+pub struct ScopedVault {
+    sealing_key: SealingKey<SCOPED_VAULT_PAGES, DEFAULT_VAULT_PAGE_SIZE>,
+    not_send: core::marker::PhantomData<*const ()>,
+}
+
+impl fmt::Debug for ScopedVault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopedVault")
+            .field("vault_pages", &SCOPED_VAULT_PAGES)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A handle to a background thread started by [EncryptedMem::start_vault_refresh] which periodically
+/// re-randomizes the vault pages. The thread is stopped when this handle is dropped.
+/// #### Structure
+/// ```rs
+/// pub struct VaultRefresh {
+///     stop: Arc<AtomicBool>,
+///     handle: Option<JoinHandle<()>>,
+/// }
+/// ```
+pub struct VaultRefresh {
+    stop: std::sync::Arc<core::sync::atomic::AtomicBool>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl VaultRefresh {
+    /// Stop the background thread and wait for it to finish. The thread is woken up
+    /// so this does not wait for the rest of the refresh interval.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, core::sync::atomic::Ordering::SeqCst);
+
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for VaultRefresh {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl fmt::Debug for VaultRefresh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VaultRefresh")
+            .field("running", &self.handle.is_some())
+            .finish()
+    }
+}
+
+/// An opaque handle to bytes sealed in memory by [seal], bundling the ciphertext and its nonce.
+/// The bytes are recovered using [unseal].
+/// #### Structure
+/// ```rs
+/// pub struct Sealed(EncryptedMem);
+/// ```
+#[derive(Debug)]
+pub struct Sealed(EncryptedMem);
+
+/// Encrypt `bytes` in memory without managing an [EncryptedMem] directly
+pub fn seal(bytes: &[u8]) -> MemSecurityResult<Sealed> {
+    let plaintext = zeroize::Zeroizing::new(bytes.to_vec());
+
+    let mut encrypted_mem = EncryptedMem::new();
+    encrypted_mem.encrypt(&plaintext)?;
+
+    Ok(Sealed(encrypted_mem))
+}
+
+/// Decrypt bytes that were sealed in memory using [seal].
+/// The whole allocation of the returned [ZeroizeBytes] is overwritten when it is dropped.
+pub fn unseal(sealed: &Sealed) -> MemSecurityResult<ZeroizeBytes> {
+    sealed.0.decrypt()
+}
+
+/// A map from string keys to values that are each sealed by their own [EncryptedMem].
+/// Keys are never retained, each one is replaced by its Blake3 hash keyed with the sealing key so that a memory dump
+/// does not reveal which keys are present. The fingerprint of the key is also the associated data of its value,
+/// which prevents a sealed value from being moved under another key. Values are wiped when they are replaced,
+/// removed or when the store is dropped.
+/// #### Structure
+/// ```rs
+/// pub struct SealedStore(HashMap<blake3::Hash, EncryptedMem>);
+/// ```
+#[derive(Default)]
+pub struct SealedStore(std::collections::HashMap<blake3::Hash, EncryptedMem>);
+
+impl SealedStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seal `value` under `key`, wiping the value previously stored under the same key if any
+    pub fn put(&mut self, key: &str, value: &[u8]) -> MemSecurityResult<()> {
+        let fingerprint = Self::key_fingerprint(key);
+        let plaintext = zeroize::Zeroizing::new(value.to_vec());
+
+        let mut encrypted_mem = EncryptedMemBuilder::new()
+            .aad(fingerprint.as_bytes())
+            .build()?;
+        encrypted_mem.encrypt(&plaintext)?;
+
+        if let Some(mut previous) = self.0.insert(fingerprint, encrypted_mem) {
+            previous.wipe();
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt the value stored under `key`, returning `Ok(None)` if there is no such key
+    pub fn get(&self, key: &str) -> MemSecurityResult<Option<ZeroizeBytes>> {
+        self.0
+            .get(&Self::key_fingerprint(key))
+            .map(EncryptedMem::decrypt)
+            .transpose()
+    }
+
+    /// Remove and wipe the value stored under `key`, returning `true` if there was one
+    pub fn remove(&mut self, key: &str) -> bool {
+        match self.0.remove(&Self::key_fingerprint(key)) {
+            Some(mut encrypted_mem) => {
+                encrypted_mem.wipe();
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if a value is stored under `key`
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(&Self::key_fingerprint(key))
+    }
+
+    /// The number of values in the store
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the store holds no values
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Hash the key with Blake3 keyed with the sealing key
+    fn key_fingerprint(key: &str) -> blake3::Hash {
+        EncryptedMem::blake3_hmac(ZeroizeBytes::new_with_data(key.as_bytes()))
+    }
+}
+
+impl fmt::Debug for SealedStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SealedStore")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl Drop for SealedStore {
+    fn drop(&mut self) {
+        self.0
+            .values_mut()
+            .for_each(|encrypted_mem| encrypted_mem.wipe());
+    }
+}
+
+/// A monotonic `u64` counter, such as a sequence number or a replay counter, which is kept sealed
+/// so it can neither be read nor changed by inspecting memory without the change being detected on the next use.
+/// #### Structure
+/// ```rs
+/// pub struct SealedCounter(EncryptedMem);
+/// ```
+#[derive(Debug)]
+pub struct SealedCounter(EncryptedMem);
+
+impl SealedCounter {
+    /// Seal a counter starting at `initial`
+    pub fn new(initial: u64) -> MemSecurityResult<Self> {
+        Ok(SealedCounter(Self::seal_value(initial)?))
+    }
+
+    /// Decrypt the current value of the counter
+    pub fn get(&self) -> MemSecurityResult<u64> {
+        let plaintext = self.0.decrypt_zeroizing()?;
+
+        let mut value = [0u8; core::mem::size_of::<u64>()];
+
+        if plaintext.len() != value.len() {
+            return Err(MemSecurityErr::UnexpectedPlaintextLength {
+                expected: value.len(),
+                found: plaintext.len(),
+            });
+        }
+
+        value.copy_from_slice(&plaintext);
+        let outcome = u64::from_be_bytes(value);
+        zeroize::Zeroize::zeroize(&mut value);
+
+        Ok(outcome)
+    }
+
+    /// Add one to the counter and seal it again under a fresh nonce, returning the new value.
+    /// Returns [MemSecurityErr::CounterOverflow] instead of wrapping around to zero, leaving the counter unchanged.
+    pub fn increment(&mut self) -> MemSecurityResult<u64> {
+        let value = self
+            .get()?
+            .checked_add(1)
+            .ok_or(MemSecurityErr::CounterOverflow)?;
+
+        self.0 = Self::seal_value(value)?;
+
+        Ok(value)
+    }
+
+    /// Seal `value` in big-endian under a fresh nonce
+    fn seal_value(value: u64) -> MemSecurityResult<EncryptedMem> {
+        let mut encrypted_mem = EncryptedMem::new();
+        encrypted_mem.encrypt(&ZeroizeArray::new(value.to_be_bytes()))?;
+
+        Ok(encrypted_mem)
+    }
+}
+
+/// A sealed slot that several threads can read and rotate without a lock. The current [EncryptedMem] is held
+/// behind an atomically swapped pointer and is replaced only if it still has the fingerprint the caller expects,
+/// so of two concurrent updates exactly one wins. The replaced [EncryptedMem] is zeroized once no reader holds it.
+/// #### Structure
+/// ```rs
+/// pub struct AtomicSealed(ArcSwap<EncryptedMem>);
+/// ```
+#[cfg(feature = "atomic_sealed")]
+pub struct AtomicSealed(arc_swap::ArcSwap<EncryptedMem>);
+
+#[cfg(feature = "atomic_sealed")]
+impl AtomicSealed {
+    /// Seal `plaintext` into a new slot
+    pub fn new<T: zeroize::Zeroize + AsRef<[u8]>>(plaintext: &T) -> MemSecurityResult<Self> {
+        let mut encrypted_mem = EncryptedMem::new();
+        encrypted_mem.encrypt(plaintext)?;
+
+        Ok(AtomicSealed(arc_swap::ArcSwap::from_pointee(encrypted_mem)))
+    }
+
+    /// The Blake3 hash of the current ciphertext which identifies the sealed value for [AtomicSealed::compare_and_seal].
+    /// Every seal uses a fresh nonce so sealing the same plaintext again yields a different fingerprint.
+    pub fn fingerprint(&self) -> blake3::Hash {
+        *self.0.load().fingerprint()
+    }
+
+    /// Decrypt the current sealed value
+    pub fn decrypt(&self) -> MemSecurityResult<ZeroizeBytes> {
+        self.0.load().decrypt()
+    }
+
+    /// Seal `new_plaintext` under a fresh nonce and store it only if the current value still has the `expected`
+    /// fingerprint. Returns `Ok(true)` if this update won and `Ok(false)` if the value was changed by another
+    /// thread first, in which case the caller should read the new fingerprint and retry.
+    /// `new_plaintext` is wiped before returning whether the update won or lost.
+    pub fn compare_and_seal<T: zeroize::Zeroize + AsRef<[u8]>>(
+        &self,
+        expected: &blake3::Hash,
+        mut new_plaintext: T,
+    ) -> MemSecurityResult<bool> {
+        let current = self.0.load_full();
+
+        if current.fingerprint() != expected {
+            new_plaintext.zeroize();
+
+            return Ok(false);
+        }
+
+        let mut encrypted_mem = EncryptedMem::new();
+        let outcome = encrypted_mem.encrypt(&new_plaintext);
+        new_plaintext.zeroize();
+        outcome?;
+
+        let previous = self
+            .0
+            .compare_and_swap(&current, std::sync::Arc::new(encrypted_mem));
+
+        Ok(std::sync::Arc::ptr_eq(&previous, &current))
+    }
+}
+
+#[cfg(feature = "atomic_sealed")]
+impl fmt::Debug for AtomicSealed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AtomicSealed").field(&self.0.load()).finish()
+    }
+}
+
+/// Check whether this process is allowed to `mlock` a region of `bytes` bytes by locking and immediately
+/// unlocking a scratch allocation of that size. This detects a low `RLIMIT_MEMLOCK`, such as the default
+/// 64 KiB limit for non-root users, at startup instead of silently holding secrets in swappable memory.
+#[allow(unsafe_code)]
+pub fn can_mlock(bytes: usize) -> bool {
+    let mut scratch = Vec::new();
+
+    // A region that cannot even be allocated cannot be locked
+    if scratch.try_reserve_exact(bytes).is_err() {
+        return false;
+    }
+    scratch.resize(bytes, 0u8);
+
+    let locked = unsafe { memsec::mlock(scratch.as_mut_ptr(), scratch.len()) };
+
+    if locked {
+        unsafe { memsec::munlock(scratch.as_mut_ptr(), scratch.len()) };
+    }
+
+    locked
+}
+
+/// The storage holding the vault pages of a [SealingKey]
+#[cfg(not(feature = "guard_pages"))]
+type VaultStorage<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> =
+    VaultPagesLayout<VAULT_PAGES, VAULT_PAGE_SIZE>;
+
+/// The storage holding the vault pages of a [SealingKey] surrounded by guard pages
+#[cfg(feature = "guard_pages")]
+type VaultStorage<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> =
+    guard_pages::GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>;
+
+#[cfg(feature = "guard_pages")]
+mod guard_pages {
+    use super::VaultPagesLayout;
+    use core::{
+        ops::{Deref, DerefMut},
+        ptr::NonNull,
+    };
+
+    /// Vault pages allocated with `memsec::malloc` which places inaccessible (`PROT_NONE`) guard pages
+    /// before and after the `mlock`ed region so that an over-read or over-write adjacent to the key material
+    /// faults immediately instead of silently touching the key.
+    pub(crate) struct GuardedPages<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>(
+        NonNull<VaultPagesLayout<VAULT_PAGES, VAULT_PAGE_SIZE>>,
+    );
+
+    impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>
+        GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+        #[allow(unsafe_code)]
+        pub(crate) fn new_zeroed() -> Self {
+            let mut ptr =
+                unsafe { memsec::malloc::<VaultPagesLayout<VAULT_PAGES, VAULT_PAGE_SIZE>>() }
+                    .expect("Unable to allocate the guarded vault pages"); // Panic since the sealing key cannot exist without its pages
+
+            unsafe { ptr.as_mut() }
+                .iter_mut()
+                .for_each(|page| page.fill(0));
+
+            GuardedPages(ptr)
+        }
+    }
+
+    impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> Deref
+        for GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+        type Target = VaultPagesLayout<VAULT_PAGES, VAULT_PAGE_SIZE>;
+
+        #[allow(unsafe_code)]
+        fn deref(&self) -> &Self::Target {
+            unsafe { self.0.as_ref() }
+        }
+    }
+
+    impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> DerefMut
+        for GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+        #[allow(unsafe_code)]
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe { self.0.as_mut() }
+        }
+    }
+
+    impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> Drop
+        for GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+        #[allow(unsafe_code)]
+        fn drop(&mut self) {
+            // `memsec::free` zeroes and unlocks the region before releasing it
+            unsafe { memsec::free(self.0) }
+        }
+    }
+
+    // The pages are uniquely owned by `GuardedPages` and only mutated through `&mut self`
+    #[allow(unsafe_code)]
+    unsafe impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> Send
+        for GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+    }
+
+    #[allow(unsafe_code)]
+    unsafe impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize> Sync
+        for GuardedPages<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+    }
+}
+
+pub use key_ops::init_sealing_key_from_seed;
+
 mod key_ops {
-    use super::SealingKey;
+    use super::{AsconNonce, ScopedVault, SealingKey};
     use crate::{
         CsprngArray, EncryptedMem, MemSecurityErr, MemSecurityResult, ZeroizeArray, ZeroizeBytes,
-        DEFAULT_VAULT_PAGES, DEFAULT_VAULT_PAGE_SIZE,
+        ZeroizeBytesArray, DEFAULT_VAULT_PAGES, DEFAULT_VAULT_PAGE_SIZE,
     };
     use ascon_aead::{
-        aead::{Aead, KeyInit},
+        aead::{Aead, AeadInPlace, KeyInit, Payload},
         Ascon128a,
     };
-    use once_cell::sync::Lazy;
-    use zeroize::{Zeroize, ZeroizeOnDrop};
+    use once_cell::sync::OnceCell;
+    use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+    type DefaultSealingKey = SealingKey<DEFAULT_VAULT_PAGES, DEFAULT_VAULT_PAGE_SIZE>;
+
+    static SEALING_KEY: OnceCell<RwLock<DefaultSealingKey>> = OnceCell::new();
+
+    fn sealing_key_lock() -> &'static RwLock<DefaultSealingKey> {
+        SEALING_KEY.get_or_init(|| RwLock::new(SealingKey::new()))
+    }
+
+    fn sealing_key() -> RwLockReadGuard<'static, DefaultSealingKey> {
+        sealing_key_lock()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn sealing_key_mut() -> RwLockWriteGuard<'static, DefaultSealingKey> {
+        sealing_key_lock()
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Build the vault pages of the sealing key from an externally supplied high-entropy `seed`,
+    /// for example one provided by an HSM, instead of the OS CSPRNG. The seed is expanded to fill
+    /// the pages using the extendable output of blake3 so the same seed always yields the same sealing key.
+    ///
+    /// This must be called once before the first encryption or decryption, otherwise the sealing key
+    /// has already been generated from the OS CSPRNG and [MemSecurityErr::SealingKeyAlreadyInitialized] is returned.
+    /// The seed must be at least [crate::MIN_SEALING_KEY_SEED_LEN] bytes long.
+    pub fn init_sealing_key_from_seed(seed: &ZeroizeBytes) -> MemSecurityResult<()> {
+        let seed = seed.expose_borrowed();
+
+        if seed.len() < crate::MIN_SEALING_KEY_SEED_LEN {
+            return Err(MemSecurityErr::SeedTooShort {
+                min: crate::MIN_SEALING_KEY_SEED_LEN,
+                found: seed.len(),
+            });
+        }
+
+        if SEALING_KEY.get().is_some() {
+            return Err(MemSecurityErr::SealingKeyAlreadyInitialized);
+        }
+
+        SEALING_KEY
+            .set(RwLock::new(SealingKey::from_seed(seed)))
+            .map_err(|_| MemSecurityErr::SealingKeyAlreadyInitialized)
+    }
+
+    impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>
+        SealingKey<VAULT_PAGES, VAULT_PAGE_SIZE>
+    {
+        fn new() -> Self {
+            Self::from_storage(Self::random_storage(), Self::random_storage())
+        }
+
+        /// Expand `seed` into the vault pages using the blake3 XOF. The masks are still random
+        /// so only the unmasked pages, and therefore the derived key, are determined by the seed.
+        fn from_seed(seed: &[u8]) -> Self {
+            let masks = Self::random_storage();
+            let mut pages = Self::zeroed_storage();
+
+            let mut expander = blake3::Hasher::new_derive_key("memsecurity sealing key seed");
+            expander.update(seed);
+            let mut output_reader = expander.finalize_xof();
+            expander.zeroize();
+
+            let mut expanded = [0u8; VAULT_PAGE_SIZE];
+
+            pages.iter_mut().zip(masks.iter()).for_each(|(page, mask)| {
+                output_reader.fill(&mut expanded);
+
+                page.iter_mut()
+                    .zip(expanded.iter().zip(mask.iter()))
+                    .for_each(|(byte, (expanded_byte, mask_byte))| {
+                        *byte = expanded_byte ^ mask_byte
+                    });
+            });
+
+            expanded.zeroize();
+            output_reader.zeroize();
+
+            Self::from_storage(pages, masks)
+        }
+
+        fn from_storage(
+            pages: super::VaultStorage<VAULT_PAGES, VAULT_PAGE_SIZE>,
+            masks: super::VaultStorage<VAULT_PAGES, VAULT_PAGE_SIZE>,
+        ) -> Self {
+            const {
+                assert!(
+                    VAULT_PAGES * VAULT_PAGE_SIZE >= crate::MIN_VAULT_ENTROPY_BYTES,
+                    "The vault must hold at least `MIN_VAULT_ENTROPY_BYTES` random bytes"
+                )
+            };
+
+            let mut outcome = SealingKey(pages, masks);
+            outcome.lock_pages();
+
+            let mut kek = [0u8; blake3::OUT_LEN];
+            outcome.kek(None, &mut kek);
+            assert_ne!(
+                kek,
+                [0u8; blake3::OUT_LEN],
+                "The sealing key derived from the vault is all zeroes"
+            );
+            kek.zeroize();
+
+            outcome
+        }
+
+        fn zeroed_storage() -> super::VaultStorage<VAULT_PAGES, VAULT_PAGE_SIZE> {
+            #[cfg(not(feature = "guard_pages"))]
+            let pages = [[0u8; VAULT_PAGE_SIZE]; VAULT_PAGES];
+            #[cfg(feature = "guard_pages")]
+            let pages = super::guard_pages::GuardedPages::new_zeroed();
+
+            pages
+        }
+
+        fn random_storage() -> super::VaultStorage<VAULT_PAGES, VAULT_PAGE_SIZE> {
+            let mut pages = Self::zeroed_storage();
+
+            (0..VAULT_PAGES).for_each(|vault_page_index| {
+                pages[vault_page_index] = CsprngArray::<VAULT_PAGE_SIZE>::gen().expose();
+            });
+
+            // A dead CSPRNG producing a zero vault would make all ciphertexts trivially decryptable
+            assert!(
+                pages.iter().all(|page| page.iter().any(|byte| *byte != 0)),
+                "The CSPRNG generated an all-zero vault page, the entropy source is broken"
+            );
+
+            pages
+        }
+
+        /// XOR the same fresh random bytes into every page and its mask. The raw bytes in memory change
+        /// while the unmasked pages, and therefore the derived key, stay the same.
+        fn refresh(&mut self) {
+            (0..VAULT_PAGES).for_each(|vault_page_index| {
+                let noise = CsprngArray::<VAULT_PAGE_SIZE>::gen();
+
+                self.0[vault_page_index]
+                    .iter_mut()
+                    .zip(self.1[vault_page_index].iter_mut())
+                    .zip(noise.expose_borrowed())
+                    .for_each(|((page_byte, mask_byte), noise_byte)| {
+                        *page_byte ^= noise_byte;
+                        *mask_byte ^= noise_byte;
+                    });
+            });
+        }
+
+        #[allow(unsafe_code)]
+        fn lock_pages(&mut self) {
+            self.0
+                .iter_mut()
+                .chain(self.1.iter_mut())
+                .for_each(|page| unsafe {
+                    memsec::mlock(page.as_mut_slice().as_mut_ptr(), VAULT_PAGE_SIZE);
+                    //TODO Handle this bool
+                });
+        }
+
+        #[allow(unsafe_code)]
+        fn munlock_pages(&mut self) {
+            self.0
+                .iter_mut()
+                .chain(self.1.iter_mut())
+                .for_each(|page| unsafe {
+                    memsec::munlock(page.as_mut_slice().as_mut_ptr(), VAULT_PAGE_SIZE);
+                    //TODO Handle this bool
+                });
+        }
+
+        /// Derive the key encryption key from the unmasked vault pages into `out`. Each unmasked page is hashed
+        /// on its own and the page digests are then hashed in page order, so the key is the same whether the pages
+        /// are hashed sequentially or in parallel using the `rayon` feature. When a `context` is given the digests
+        /// are hashed in blake3 key derivation mode under that context so each context gets its own key.
+        fn kek(&self, context: Option<&str>, out: &mut [u8; blake3::OUT_LEN]) {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("kek", vault_pages = VAULT_PAGES).entered();
+
+            #[cfg(not(feature = "rayon"))]
+            let mut page_digests = self
+                .0
+                .iter()
+                .zip(self.1.iter())
+                .map(|(page, mask)| Self::page_digest(page, mask))
+                .collect::<Vec<blake3::Hash>>();
+
+            #[cfg(feature = "rayon")]
+            let mut page_digests = {
+                use rayon::prelude::*;
+
+                self.0
+                    .par_iter()
+                    .zip(self.1.par_iter())
+                    .map(|(page, mask)| Self::page_digest(page, mask))
+                    .collect::<Vec<blake3::Hash>>()
+            };
+
+            let mut hasher = match context {
+                Some(context) => blake3::Hasher::new_derive_key(context),
+                None => blake3::Hasher::new(),
+            };
+
+            page_digests.iter_mut().for_each(|page_digest| {
+                hasher.update(page_digest.as_bytes());
+                page_digest.zeroize();
+            });
+
+            let mut hash = hasher.finalize();
+            out.copy_from_slice(hash.as_bytes());
+            hash.zeroize();
+            hasher.zeroize();
+        }
+
+        /// Hash one page XOR-ed with its mask, wiping the unmasked copy afterwards
+        fn page_digest(page: &[u8; VAULT_PAGE_SIZE], mask: &[u8; VAULT_PAGE_SIZE]) -> blake3::Hash {
+            let mut unmasked = [0u8; VAULT_PAGE_SIZE];
+
+            unmasked
+                .iter_mut()
+                .zip(page.iter().zip(mask.iter()))
+                .for_each(|(byte, (page_byte, mask_byte))| *byte = page_byte ^ mask_byte);
+
+            let digest = blake3::hash(&unmasked);
+            unmasked.zeroize();
+
+            digest
+        }
+    }
+
+    impl ScopedVault {
+        /// Generate a new sealing key owned by this vault from the CSPRNG
+        pub fn new() -> Self {
+            ScopedVault {
+                sealing_key: SealingKey::new(),
+                not_send: core::marker::PhantomData,
+            }
+        }
+
+        /// Run `f` with the vault of the current thread, which is created on first use
+        /// and zeroized when the thread exits
+        pub fn with_thread_vault<R>(f: impl FnOnce(&ScopedVault) -> R) -> R {
+            std::thread_local! {
+                static THREAD_VAULT: ScopedVault = ScopedVault::new();
+            }
+
+            THREAD_VAULT.with(f)
+        }
+
+        /// Encrypt `plaintext` into `encrypted_mem` using the sealing key of this vault. The nonce, associated data
+        /// and context of `encrypted_mem` are used the same way as by [EncryptedMem::encrypt].
+        pub fn encrypt<T: Zeroize + AsRef<[u8]>>(
+            &self,
+            encrypted_mem: &mut EncryptedMem,
+            plaintext: &T,
+        ) -> MemSecurityResult<()> {
+            let nonce = encrypted_mem.next_nonce()?;
 
-    #[allow(clippy::redundant_closure)]
-    static SEALING_KEY: Lazy<SealingKey<DEFAULT_VAULT_PAGE_SIZE, DEFAULT_VAULT_PAGES>> =
-        Lazy::new(|| SealingKey::new());
+            let kek = KekGuard::derive(&self.sealing_key, encrypted_mem.context.as_deref());
 
-    impl<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>
-        SealingKey<VAULT_PAGES, VAULT_PAGE_SIZE>
-    {
-        fn new() -> Self {
-            let mut pages = [[0u8; VAULT_PAGE_SIZE]; VAULT_PAGES];
+            let outcome = kek
+                .cipher()
+                .encrypt(
+                    EncryptedMem::as_ascon_nonce(&nonce),
+                    encrypted_mem.payload(plaintext.as_ref()),
+                )
+                .map_err(|_| MemSecurityErr::EncryptionFailed);
 
-            (0..VAULT_PAGES).for_each(|vault_page_index| {
-                pages[vault_page_index] = CsprngArray::<VAULT_PAGE_SIZE>::gen().expose();
-            });
+            drop(kek);
 
-            let mut outcome = SealingKey(pages);
-            outcome.lock_pages();
+            encrypted_mem.commit_sealed(nonce, &outcome?);
 
-            outcome
+            Ok(())
         }
 
-        #[allow(unsafe_code)]
-        fn lock_pages(&mut self) {
-            self.0.iter_mut().for_each(|page| unsafe {
-                memsec::mlock(page.as_mut_slice().as_mut_ptr(), VAULT_PAGE_SIZE);
-                //TODO Handle this bool
-            });
+        /// Decrypt a secret sealed by [ScopedVault::encrypt] on this vault.
+        /// Returns [MemSecurityErr::CiphertextTooShort] if the ciphertext cannot hold a tag
+        /// and [MemSecurityErr::AuthenticationFailed] if it was sealed by another vault or cannot be authenticated.
+        pub fn decrypt(&self, encrypted_mem: &EncryptedMem) -> MemSecurityResult<ZeroizeBytes> {
+            let ciphertext = encrypted_mem.tagged_ciphertext(crate::ASCON128_TAG_LEN)?;
+
+            let kek = KekGuard::derive(&self.sealing_key, encrypted_mem.context.as_deref());
+
+            let outcome = kek
+                .cipher()
+                .decrypt(
+                    encrypted_mem.ascon_nonce(),
+                    encrypted_mem.payload(ciphertext),
+                )
+                .map(Zeroizing::new)
+                .map_err(|_| MemSecurityErr::AuthenticationFailed);
+
+            drop(kek);
+
+            Ok(ZeroizeBytes::new_with_data(&outcome?))
         }
+    }
 
-        #[allow(unsafe_code)]
-        fn munlock_pages(&mut self) {
-            self.0.iter_mut().for_each(|page| unsafe {
-                memsec::munlock(page.as_mut_slice().as_mut_ptr(), VAULT_PAGE_SIZE);
-                //TODO Handle this bool
-            });
+    impl Default for ScopedVault {
+        fn default() -> Self {
+            Self::new()
         }
+    }
 
-        fn kek(&self) -> [u8; blake3::OUT_LEN] {
-            let mut hasher = blake3::Hasher::new();
-            self.0.iter().for_each(|page| {
-                hasher.update(page);
-            });
+    /// Holds the key encryption key derived from the vault on the heap so its address is stable while it is
+    /// `mlock`ed. The key is locked when the guard is created and unlocked and wiped when the guard is dropped,
+    /// which also happens on early returns and while unwinding from a panic.
+    struct KekGuard {
+        kek: Box<[u8; blake3::OUT_LEN]>,
+        locked: bool,
+    }
 
-            *hasher.finalize().as_bytes()
+    impl KekGuard {
+        fn new(context: Option<&str>) -> Self {
+            Self::derive(&sealing_key(), context)
         }
 
         #[allow(unsafe_code)]
-        fn mlock_kek(&self, ptr: *mut u8) -> bool {
-            unsafe { memsec::mlock(ptr, blake3::OUT_LEN) }
+        fn derive<const VAULT_PAGES: usize, const VAULT_PAGE_SIZE: usize>(
+            sealing_key: &SealingKey<VAULT_PAGES, VAULT_PAGE_SIZE>,
+            context: Option<&str>,
+        ) -> Self {
+            let mut kek = Box::new([0u8; blake3::OUT_LEN]);
+            let locked = unsafe { memsec::mlock(kek.as_mut_ptr(), blake3::OUT_LEN) };
+
+            sealing_key.kek(context, &mut kek);
+
+            KekGuard { kek, locked }
+        }
+
+        fn as_bytes(&self) -> &[u8; blake3::OUT_LEN] {
+            &self.kek
+        }
+
+        fn cipher(&self) -> Ascon128a {
+            Ascon128a::new(self.kek[0..16].as_ref().into())
         }
+    }
 
+    impl Drop for KekGuard {
         #[allow(unsafe_code)]
-        fn munlock_kek(&self, ptr: *mut u8) -> bool {
-            unsafe { memsec::munlock(ptr, blake3::OUT_LEN) }
+        fn drop(&mut self) {
+            // `munlock` zeroes the region before unlocking it
+            if self.locked {
+                unsafe { memsec::munlock(self.kek.as_mut_ptr(), blake3::OUT_LEN) };
+            }
+
+            self.kek.zeroize();
+
+            debug_assert_eq!(*self.kek, [0u8; blake3::OUT_LEN]);
         }
     }
 
@@ -173,7 +1312,10 @@ mod key_ops {
         for SealingKey<VAULT_PAGES, VAULT_PAGE_SIZE>
     {
         fn zeroize(&mut self) {
-            self.0 = [[0u8; VAULT_PAGE_SIZE]; VAULT_PAGES];
+            self.0
+                .iter_mut()
+                .chain(self.1.iter_mut())
+                .for_each(|page| page.zeroize());
         }
     }
 
@@ -191,68 +1333,597 @@ mod key_ops {
             #[cfg(debug_assertions)]
             self.0
                 .iter()
-                .for_each(|page| debug_assert_eq!(page, &[0u8; VAULT_PAGE_SIZE]))
+                .chain(self.1.iter())
+                .for_each(|page| debug_assert!(is_zeroed(page), "A vault page was not zeroized"))
         }
     }
 
+    /// Check that every byte is zero by OR-ing the bytes together a `usize` word at a time,
+    /// which keeps the verification of large vaults fast in debug builds.
+    /// There is no early return so the time taken does not depend on the contents.
+    #[cfg(debug_assertions)]
+    fn is_zeroed(bytes: &[u8]) -> bool {
+        const WORD_LEN: usize = core::mem::size_of::<usize>();
+
+        let words = bytes.chunks_exact(WORD_LEN);
+        let remainder = words
+            .remainder()
+            .iter()
+            .fold(0u8, |accumulator, byte| accumulator | byte);
+
+        let accumulator = words.fold(0usize, |accumulator, word| {
+            let mut buffer = [0u8; WORD_LEN];
+            buffer.copy_from_slice(word);
+
+            accumulator | usize::from_ne_bytes(buffer)
+        });
+
+        accumulator == 0 && remainder == 0
+    }
+
     impl EncryptedMem {
-        /// Performs an encryption operation.
+        /// Performs an encryption operation. The ciphertext and nonce are stored in this [EncryptedMem]
+        /// so success or failure is the only outcome returned.
         pub fn encrypt<T: Zeroize + AsRef<[u8]>>(
             &mut self,
             plaintext: &T,
-        ) -> MemSecurityResult<&mut Self> {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
+        ) -> MemSecurityResult<()> {
+            #[cfg(feature = "tracing")]
+            let span = tracing::debug_span!(
+                "encrypt",
+                plaintext_len = plaintext.as_ref().len(),
+                ciphertext_fingerprint = tracing::field::Empty
+            )
+            .entered();
+
+            let nonce = self.next_nonce()?;
+
+            let kek = KekGuard::new(self.context.as_deref());
 
-            let cipher = Ascon128a::new(kek[0..16].as_ref().into());
+            let cipher = kek.cipher();
 
-            let outcome = match cipher.encrypt(&self.nonce, plaintext.as_ref()) {
+            let outcome = match cipher.encrypt(
+                Self::as_ascon_nonce(&nonce),
+                self.payload(plaintext.as_ref()),
+            ) {
                 Ok(ciphertext) => Ok(ciphertext),
-                Err(_) => Err(MemSecurityErr::EncryptionErr),
+                Err(_) => Err(MemSecurityErr::EncryptionFailed),
             };
 
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
+            drop(kek);
 
-            debug_assert_eq!(kek, [0u8; blake3::OUT_LEN]);
+            self.commit_sealed(nonce, &outcome?);
 
-            self.ciphertext = ZeroizeBytes::new_with_data(&outcome?);
+            // Hashing the ciphertext is only worth it when a subscriber records the span
+            #[cfg(feature = "tracing")]
+            if !span.is_disabled() {
+                span.record(
+                    "ciphertext_fingerprint",
+                    tracing::field::display(blake3::hash(self.ciphertext.expose_borrowed())),
+                );
+            }
 
-            Ok(self)
+            Ok(())
+        }
+
+        /// Encrypt `plaintext` into the caller provided `out` buffer instead of allocating a new ciphertext,
+        /// which allows a pre-allocated buffer to be reused across calls. The previous contents of `out` are wiped
+        /// before `nonce || ciphertext || tag` is written, where the nonce is a fresh random nonce drawn for every call.
+        /// `out` must have room for the [crate::ASCON128_NONCE_LEN] byte nonce, the plaintext and the
+        /// [crate::ASCON128_TAG_LEN] byte tag otherwise [MemSecurityErr::CapacityExceeded] is returned.
+        ///
+        /// The context and associated data of this [EncryptedMem] are used but its own nonce and ciphertext
+        /// are left untouched, so it can still decrypt the secret it holds.
+        pub fn encrypt_into<T: Zeroize + AsRef<[u8]>, const N: usize>(
+            &self,
+            plaintext: &T,
+            out: &mut ZeroizeBytesArray<N>,
+        ) -> MemSecurityResult<()> {
+            let plaintext = plaintext.as_ref();
+
+            if crate::ASCON128_NONCE_LEN + plaintext.len() + crate::ASCON128_TAG_LEN > N {
+                return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+            }
+
+            let nonce = Self::random_nonce();
+
+            let buffer = out.wiped_bytes_mut();
+            buffer.extend_from_slice(nonce.expose_borrowed());
+            buffer.extend_from_slice(plaintext);
+
+            let kek = KekGuard::new(self.context.as_deref());
+
+            let cipher = kek.cipher();
+
+            let outcome = cipher
+                .encrypt_in_place_detached(
+                    Self::as_ascon_nonce(&nonce),
+                    &self.aad,
+                    &mut buffer[crate::ASCON128_NONCE_LEN..],
+                )
+                .map_err(|_| MemSecurityErr::EncryptionFailed);
+
+            drop(kek);
+
+            match outcome {
+                Ok(tag) => buffer.extend_from_slice(&tag),
+                Err(error) => {
+                    out.wiped_bytes_mut();
+
+                    return Err(error);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Decrypt `nonce || ciphertext || tag` written by [EncryptedMem::encrypt_into] using the context and
+        /// associated data of this [EncryptedMem]. Returns [MemSecurityErr::CiphertextTooShort] if `sealed` cannot
+        /// hold a nonce and a tag and [MemSecurityErr::AuthenticationFailed] if it cannot be authenticated.
+        pub fn decrypt_from<const N: usize>(
+            &self,
+            sealed: &ZeroizeBytesArray<N>,
+        ) -> MemSecurityResult<ZeroizeBytes> {
+            let sealed = sealed.expose_borrowed();
+            let min = crate::ASCON128_NONCE_LEN + crate::ASCON128_TAG_LEN;
+
+            if sealed.len() < min {
+                return Err(MemSecurityErr::CiphertextTooShort {
+                    min,
+                    found: sealed.len(),
+                });
+            }
+
+            let (nonce, ciphertext) = sealed.split_at(crate::ASCON128_NONCE_LEN);
+
+            let kek = KekGuard::new(self.context.as_deref());
+
+            let outcome = kek
+                .cipher()
+                .decrypt(AsconNonce::from_slice(nonce), self.payload(ciphertext))
+                .map(Zeroizing::new)
+                .map_err(|_| MemSecurityErr::AuthenticationFailed);
+
+            drop(kek);
+
+            Ok(ZeroizeBytes::new_with_data(&outcome?))
+        }
+
+        /// Re-randomizes the raw bytes of the vault pages in memory without changing the derived sealing key.
+        /// This frustrates cold-boot and DMA attacks that rely on the vault sitting static in memory.
+        pub fn refresh_vault() {
+            sealing_key_mut().refresh();
+        }
+
+        /// Spawn a background thread that calls [EncryptedMem::refresh_vault] every `interval`.
+        /// The thread runs until the returned [crate::VaultRefresh] is stopped or dropped.
+        pub fn start_vault_refresh(interval: core::time::Duration) -> crate::VaultRefresh {
+            use core::sync::atomic::{AtomicBool, Ordering};
+            use std::sync::Arc;
+
+            let stop = Arc::new(AtomicBool::new(false));
+            let thread_stop = stop.clone();
+
+            let handle = std::thread::spawn(move || loop {
+                std::thread::park_timeout(interval);
+
+                if thread_stop.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                EncryptedMem::refresh_vault();
+            });
+
+            crate::VaultRefresh {
+                stop,
+                handle: Some(handle),
+            }
+        }
+
+        /// Re-randomizes the nonce without changing the sealing key. The current ciphertext is decrypted
+        /// into a zeroizing buffer using the old nonce and then encrypted again using a fresh nonce.
+        /// This limits how long a given (key, nonce) pair has been observed in a long-lived store.
+        /// Returns [MemSecurityErr::CounterNonceReused] for a value created by [EncryptedMem::new_counter_nonce].
+        pub fn refresh_nonce(&mut self) -> MemSecurityResult<()> {
+            let plaintext = self.decrypt_zeroizing()?;
+
+            // The current nonce has been used so `encrypt` draws a fresh one
+            self.nonce_used = true;
+
+            self.encrypt(&plaintext)
+        }
+
+        /// Create an independent copy of this [EncryptedMem] by decrypting the secret into a zeroizing buffer
+        /// and encrypting it again under a fresh nonce and the same context and associated data.
+        ///
+        /// [EncryptedMem] does not implement `Clone` since copying the ciphertext and the nonce would leave two values
+        /// sharing one (key, nonce) pair. Encrypting a different plaintext into either of them would then reuse
+        /// the nonce, which breaks the confidentiality and authenticity of both ciphertexts.
+        pub fn reseal_clone(&self) -> MemSecurityResult<Self> {
+            let plaintext = self.decrypt_zeroizing()?;
+
+            let mut outcome = EncryptedMem {
+                aad: self.aad.clone(),
+                context: self.context.clone(),
+                ..EncryptedMem::new()
+            };
+            outcome.encrypt(&plaintext)?;
+
+            Ok(outcome)
         }
 
         /// Performs an decryption operation.
+        /// Returns [MemSecurityErr::CiphertextTooShort] if the ciphertext cannot hold a tag
+        /// and [MemSecurityErr::AuthenticationFailed] if the ciphertext cannot be authenticated.
         pub fn decrypt(&self) -> MemSecurityResult<ZeroizeBytes> {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!(
+                "decrypt",
+                ciphertext_len = self.ciphertext.expose_borrowed().len(),
+                ciphertext_fingerprint =
+                    tracing::field::display(blake3::hash(self.ciphertext.expose_borrowed()))
+            )
+            .entered();
+
+            let ciphertext = self.tagged_ciphertext(crate::ASCON128_TAG_LEN)?;
 
-            let cipher = Ascon128a::new(kek[0..16].as_ref().into());
+            let kek = KekGuard::new(self.context.as_deref());
 
-            let outcome =
-                match cipher.decrypt(&self.nonce, self.ciphertext.expose_borrowed().as_ref()) {
-                    Ok(plaintext) => Ok(ZeroizeBytes::new_with_data(&plaintext)),
-                    Err(_) => Err(MemSecurityErr::EncryptionErr),
-                };
+            let cipher = kek.cipher();
 
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
+            let outcome = match cipher.decrypt(self.ascon_nonce(), self.payload(ciphertext)) {
+                Ok(plaintext) => {
+                    // Wipe the aead output once it has been copied into the `ZeroizeBytes`
+                    let plaintext = Zeroizing::new(plaintext);
 
-            debug_assert_eq!(kek, [0u8; blake3::OUT_LEN]);
+                    Ok(ZeroizeBytes::new_with_data(&plaintext))
+                }
+                Err(_) => Err(MemSecurityErr::AuthenticationFailed),
+            };
+
+            drop(kek);
 
             outcome
         }
 
-        /// Hash some bytes with Blake3 using a key to create a HMAC
-        pub fn blake3_hmac<T: Zeroize + AsRef<[u8]>>(plaintext: T) -> blake3::Hash {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
+        /// Performs a decryption operation returning the plaintext in a [Zeroizing] `Vec`
+        /// for interoperability with crates that consume `Zeroizing<Vec<u8>>`.
+        /// The output of the aead is wrapped directly so no unwiped copy of the plaintext is left behind.
+        pub fn decrypt_zeroizing(&self) -> MemSecurityResult<Zeroizing<Vec<u8>>> {
+            let ciphertext = self.tagged_ciphertext(crate::ASCON128_TAG_LEN)?;
+
+            let kek = KekGuard::new(self.context.as_deref());
+
+            let outcome = kek
+                .cipher()
+                .decrypt(self.ascon_nonce(), self.payload(ciphertext))
+                .map(Zeroizing::new)
+                .map_err(|_| MemSecurityErr::AuthenticationFailed);
+
+            drop(kek);
+
+            outcome
+        }
+
+        /// Pair `msg` with the associated data of this [EncryptedMem] so the cipher authenticates both
+        fn payload<'a>(&'a self, msg: &'a [u8]) -> Payload<'a, 'a> {
+            Payload {
+                msg,
+                aad: &self.aad,
+            }
+        }
+
+        /// Borrow the ciphertext after checking it is at least `min` bytes long so that a truncated ciphertext
+        /// is reported as [MemSecurityErr::CiphertextTooShort] before the cipher is used
+        fn tagged_ciphertext(&self, min: usize) -> MemSecurityResult<&[u8]> {
+            let ciphertext = self.ciphertext.expose_borrowed();
+
+            if ciphertext.len() < min {
+                return Err(MemSecurityErr::CiphertextTooShort {
+                    min,
+                    found: ciphertext.len(),
+                });
+            }
+
+            Ok(ciphertext)
+        }
+
+        /// Performs an encryption operation after padding the plaintext to the next multiple of `block` bytes
+        /// so that the length of the ciphertext only reveals the number of blocks, not the exact length of the secret.
+        /// The padding is PKCS#7 which always adds between 1 and `block` bytes, each holding the number of bytes added,
+        /// and is built in a zeroizing buffer. `block` must be between 1 and 255 otherwise
+        /// [MemSecurityErr::InvalidPaddingBlock] is returned. Use [EncryptedMem::decrypt_padded] to decrypt it.
+        pub fn encrypt_padded<T: Zeroize + AsRef<[u8]>>(
+            &mut self,
+            plaintext: &T,
+            block: usize,
+        ) -> MemSecurityResult<()> {
+            let pad_len = Self::pad_len(plaintext.as_ref().len(), block)?;
+
+            let mut padded = ZeroizeBytes::new_with_capacity(plaintext.as_ref().len() + pad_len);
+            padded.extend_from_slice(plaintext.as_ref());
+            padded.extend(core::iter::repeat_n(pad_len as u8, pad_len));
+
+            self.encrypt(&padded)
+        }
+
+        /// Performs a decryption operation of a secret encrypted using [EncryptedMem::encrypt_padded]
+        /// with the same `block` size and strips the padding. Every byte of the last block is checked in constant time
+        /// so the time taken does not depend on the length of the padding. [MemSecurityErr::InvalidPadding]
+        /// is returned if the padding is malformed.
+        pub fn decrypt_padded(&self, block: usize) -> MemSecurityResult<ZeroizeBytes> {
+            use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+            Self::pad_len(0, block)?;
+
+            let padded = self.decrypt_zeroizing()?;
+            let bytes = padded.as_slice();
+            let len = bytes.len();
+
+            if len == 0 || len % block != 0 {
+                return Err(MemSecurityErr::InvalidPadding);
+            }
+
+            let pad_len = bytes[len - 1];
+
+            let mut valid = !pad_len.ct_eq(&0) & !pad_len.ct_gt(&(block as u8));
+
+            bytes[len - block..]
+                .iter()
+                .rev()
+                .enumerate()
+                .for_each(|(index, byte)| {
+                    let in_padding = (index as u8).ct_lt(&pad_len);
+                    valid &= !in_padding | byte.ct_eq(&pad_len);
+                });
+
+            if !bool::from(valid) {
+                return Err(MemSecurityErr::InvalidPadding);
+            }
+
+            Ok(ZeroizeBytes::new_with_data(
+                &bytes[..len - pad_len as usize],
+            ))
+        }
+
+        /// The number of PKCS#7 padding bytes needed to pad `len` bytes to a multiple of `block`
+        fn pad_len(len: usize, block: usize) -> MemSecurityResult<usize> {
+            if !(1..=u8::MAX as usize).contains(&block) {
+                return Err(MemSecurityErr::InvalidPaddingBlock { block });
+            }
+
+            Ok(block - len % block)
+        }
+
+        /// Encrypt `plaintext` under a fresh random nonce and return the nonce and the ciphertext as owned
+        /// zeroizing values, for example to persist them in a database without keeping an [EncryptedMem] around.
+        /// Use [EncryptedMem::open_detached] to decrypt them.
+        pub fn seal_detached<T: Zeroize + AsRef<[u8]>>(
+            plaintext: &T,
+        ) -> MemSecurityResult<(ZeroizeArray<{ crate::ASCON128_NONCE_LEN }>, ZeroizeBytes)>
+        {
+            let mut encrypted_mem = EncryptedMem::new();
+            encrypted_mem.encrypt(plaintext)?;
+
+            let nonce = core::mem::replace(&mut encrypted_mem.nonce, ZeroizeArray::new_zeroed());
+            let ciphertext = core::mem::take(&mut encrypted_mem.ciphertext);
+
+            Ok((nonce, ciphertext))
+        }
+
+        /// Decrypt a `nonce` and `ciphertext` produced by [EncryptedMem::seal_detached]
+        pub fn open_detached(
+            nonce: &ZeroizeArray<{ crate::ASCON128_NONCE_LEN }>,
+            ciphertext: &ZeroizeBytes,
+        ) -> MemSecurityResult<ZeroizeBytes> {
+            EncryptedMem::from_parts(
+                nonce.expose_borrowed(),
+                ZeroizeBytes::new_with_data(ciphertext.expose_borrowed()),
+            )?
+            .decrypt()
+        }
+
+        /// Performs an encryption operation layering an independent MAC over the AEAD, for defense in depth
+        /// against a flaw in the cipher. The plaintext is encrypted with Ascon128a and the nonce and ciphertext are
+        /// then authenticated with a keyed Blake3 hash. The cipher key and the MAC key are derived from the sealing key
+        /// under separate contexts so neither key is used for both purposes. The MAC is appended to the ciphertext
+        /// so the outcome can only be decrypted using [EncryptedMem::decrypt_eme].
+        pub fn encrypt_eme<T: Zeroize + AsRef<[u8]>>(
+            &mut self,
+            plaintext: &T,
+        ) -> MemSecurityResult<&mut Self> {
+            let nonce = self.next_nonce()?;
+
+            let (cipher_key, mac_key) = self.eme_subkeys();
+
+            let mut ciphertext = Ascon128a::new(cipher_key[0..16].as_ref().into())
+                .encrypt(
+                    Self::as_ascon_nonce(&nonce),
+                    self.payload(plaintext.as_ref()),
+                )
+                .map_err(|_| MemSecurityErr::EncryptionFailed)?;
+
+            let mac = Self::eme_mac(&mac_key, nonce.expose_borrowed(), &ciphertext);
+            ciphertext.extend_from_slice(mac.as_bytes());
+
+            self.commit_sealed(nonce, &ciphertext);
+
+            Ok(self)
+        }
+
+        /// Performs a decryption operation of a secret encrypted using [EncryptedMem::encrypt_eme].
+        /// The outer MAC is verified in constant time before the AEAD decryption is attempted and
+        /// [MemSecurityErr::AuthenticationFailed] is returned if either check fails.
+        pub fn decrypt_eme(&self) -> MemSecurityResult<ZeroizeBytes> {
+            let ciphertext = self.tagged_ciphertext(crate::ASCON128_TAG_LEN + blake3::OUT_LEN)?;
+
+            let (ciphertext, mac) = ciphertext.split_at(ciphertext.len() - blake3::OUT_LEN);
+
+            let (cipher_key, mac_key) = self.eme_subkeys();
+
+            // Comparing `blake3::Hash` values runs in constant time
+            let mut expected_mac = [0u8; blake3::OUT_LEN];
+            expected_mac.copy_from_slice(mac);
+
+            if Self::eme_mac(&mac_key, self.nonce.expose_borrowed(), ciphertext)
+                != blake3::Hash::from(expected_mac)
+            {
+                return Err(MemSecurityErr::AuthenticationFailed);
+            }
+
+            let plaintext = Ascon128a::new(cipher_key[0..16].as_ref().into())
+                .decrypt(self.ascon_nonce(), self.payload(ciphertext))
+                .map(Zeroizing::new)
+                .map_err(|_| MemSecurityErr::AuthenticationFailed)?;
+
+            Ok(ZeroizeBytes::new_with_data(&plaintext))
+        }
+
+        /// Derive the independent cipher and MAC keys used by [EncryptedMem::encrypt_eme]
+        #[allow(clippy::type_complexity)]
+        fn eme_subkeys(
+            &self,
+        ) -> (
+            Zeroizing<[u8; blake3::OUT_LEN]>,
+            Zeroizing<[u8; blake3::OUT_LEN]>,
+        ) {
+            let kek = KekGuard::new(self.context.as_deref());
+
+            let cipher_key = Zeroizing::new(blake3::derive_key(
+                "memsecurity eme cipher key",
+                kek.as_bytes(),
+            ));
+            let mac_key = Zeroizing::new(blake3::derive_key(
+                "memsecurity eme mac key",
+                kek.as_bytes(),
+            ));
+
+            drop(kek);
+
+            (cipher_key, mac_key)
+        }
+
+        /// Authenticate the nonce and the ciphertext produced by [EncryptedMem::encrypt_eme]
+        fn eme_mac(
+            mac_key: &[u8; blake3::OUT_LEN],
+            nonce: &[u8],
+            ciphertext: &[u8],
+        ) -> blake3::Hash {
+            let mut hasher = blake3::Hasher::new_keyed(mac_key);
+            hasher.update(nonce);
+            hasher.update(ciphertext);
+
+            let outcome = hasher.finalize();
+            hasher.zeroize();
+
+            outcome
+        }
+
+        /// Performs an encryption operation using a key derived from both the sealing key and a user supplied
+        /// `password`. The password is stretched with Argon2id using the `salt` and `params` specified and the
+        /// outcome is mixed with the sealing key using a keyed Blake3 hash. Recovering the secret therefore requires
+        /// both the memory of this process and the password. The `salt` must be at least 8 bytes long.
+        #[cfg(feature = "argon2")]
+        pub fn encrypt_password_derived<T: Zeroize + AsRef<[u8]>>(
+            &mut self,
+            plaintext: &T,
+            password: &ZeroizeBytes,
+            salt: &[u8],
+            params: argon2::Params,
+        ) -> MemSecurityResult<&mut Self> {
+            let nonce = self.next_nonce()?;
+
+            let key = self.password_derived_key(password, salt, params)?;
+
+            let cipher = Ascon128a::new(key[0..16].as_ref().into());
+
+            let ciphertext = match cipher.encrypt(
+                Self::as_ascon_nonce(&nonce),
+                self.payload(plaintext.as_ref()),
+            ) {
+                Ok(ciphertext) => ciphertext,
+                Err(_) => return Err(MemSecurityErr::EncryptionFailed),
+            };
+
+            self.commit_sealed(nonce, &ciphertext);
+
+            Ok(self)
+        }
+
+        /// Performs a decryption operation of a secret encrypted using [EncryptedMem::encrypt_password_derived].
+        /// The same `password`, `salt` and `params` used to encrypt the secret must be provided.
+        #[cfg(feature = "argon2")]
+        pub fn decrypt_password_derived(
+            &self,
+            password: &ZeroizeBytes,
+            salt: &[u8],
+            params: argon2::Params,
+        ) -> MemSecurityResult<ZeroizeBytes> {
+            let key = self.password_derived_key(password, salt, params)?;
+
+            let ciphertext = self.tagged_ciphertext(crate::ASCON128_TAG_LEN)?;
+
+            let cipher = Ascon128a::new(key[0..16].as_ref().into());
+
+            match cipher.decrypt(self.ascon_nonce(), self.payload(ciphertext)) {
+                Ok(plaintext) => {
+                    let plaintext = Zeroizing::new(plaintext);
+
+                    Ok(ZeroizeBytes::new_with_data(&plaintext))
+                }
+                Err(_) => Err(MemSecurityErr::AuthenticationFailed),
+            }
+        }
+
+        /// Stretch the `password` with Argon2id and mix the outcome with the sealing key
+        #[cfg(feature = "argon2")]
+        fn password_derived_key(
+            &self,
+            password: &ZeroizeBytes,
+            salt: &[u8],
+            params: argon2::Params,
+        ) -> MemSecurityResult<Zeroizing<[u8; blake3::OUT_LEN]>> {
+            use argon2::{Algorithm, Argon2, Version};
+
+            let mut password_key = Zeroizing::new([0u8; blake3::OUT_LEN]);
+
+            if Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password_into(
+                    password.expose_borrowed(),
+                    salt,
+                    password_key.as_mut_slice(),
+                )
+                .is_err()
+            {
+                return Err(MemSecurityErr::PasswordDerivationError);
+            }
+
+            let kek = KekGuard::new(self.context.as_deref());
+
+            let outcome = Zeroizing::new(
+                *blake3::keyed_hash(kek.as_bytes(), password_key.as_ref()).as_bytes(),
+            );
+
+            drop(kek);
+
+            Ok(outcome)
+        }
 
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
+        /// Check whether the encrypted secret is equal to `candidate` without exposing the plaintext,
+        /// for example when verifying a password. The plaintext is decrypted into a zeroizing buffer
+        /// and compared using the constant time equality of [blake3::Hash] then wiped.
+        pub fn plaintext_eq(&self, candidate: &[u8]) -> MemSecurityResult<bool> {
+            let plaintext = self.decrypt_zeroizing()?;
 
-            let outcome = blake3::keyed_hash(&kek, plaintext.as_ref());
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
+            Ok(blake3::hash(&plaintext) == blake3::hash(candidate))
+        }
+
+        /// Hash some bytes with Blake3 using a key to create a HMAC
+        pub fn blake3_hmac<T: Zeroize + AsRef<[u8]>>(plaintext: T) -> blake3::Hash {
+            let kek = KekGuard::new(None);
 
-            debug_assert_eq!(kek, [0u8; 32]);
+            let outcome = blake3::keyed_hash(kek.as_bytes(), plaintext.as_ref());
+            drop(kek);
 
             outcome
         }
@@ -261,100 +1932,105 @@ mod key_ops {
         pub fn blake3_keyed_hash_with_array<T: Zeroize + AsRef<[u8]>>(
             plaintext_array: &[T],
         ) -> blake3::Hash {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
+            let kek = KekGuard::new(None);
 
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
-
-            let mut hasher = blake3::Hasher::new_keyed(&kek);
+            let mut hasher = blake3::Hasher::new_keyed(kek.as_bytes());
             plaintext_array.iter().for_each(|plaintext| {
                 hasher.update(plaintext.as_ref());
             });
 
             let outcome = hasher.finalize();
 
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
-
-            debug_assert_eq!(kek, [0u8; 32]);
+            drop(kek);
 
             outcome
         }
 
         /// Hash a predetermined content with Blake3 using a secret key to derive a key (HKDF)
         pub fn blake3_hkdf(plaintext: &str) -> [u8; blake3::OUT_LEN] {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
-
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
-
-            let outcome = blake3::derive_key(plaintext, &kek);
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
+            let kek = KekGuard::new(None);
 
-            debug_assert_eq!(kek, [0u8; 32]);
+            let outcome = blake3::derive_key(plaintext, kek.as_bytes());
+            drop(kek);
 
             outcome
         }
 
         /// Performs an decryption operation expecting a 16 byte array that is zeroed when dropped.
+        /// See [EncryptedMem::decrypt_into_array] for the errors returned.
         pub fn decrypt_16byte(&self) -> MemSecurityResult<ZeroizeArray<16>> {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
-
-            let cipher = Ascon128a::new(kek[0..16].as_ref().into());
-
-            let outcome =
-                match cipher.decrypt(&self.nonce, self.ciphertext.expose_borrowed().as_ref()) {
-                    Ok(plaintext) => {
-                        let plaintext_len = plaintext.len();
-                        if plaintext_len != crate::SECRET_KEY_16BYTE {
-                            return Err(MemSecurityErr::InvalidArrayLength {
-                                expected: crate::SECRET_KEY_16BYTE,
-                                found: plaintext_len,
-                            });
-                        } else {
-                            ZeroizeArray::<{ crate::SECRET_KEY_16BYTE }>::new_from_slice(&plaintext)
-                        }
-                    }
-                    Err(_) => Err(MemSecurityErr::EncryptionErr),
-                };
-
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
-
-            debug_assert_eq!(kek, [0u8; blake3::OUT_LEN]);
-
-            outcome
+            self.decrypt_into_array::<{ crate::SECRET_KEY_16BYTE }>()
         }
 
         /// Performs an decryption operation expecting a 32 byte array that is zeroed when dropped.
+        /// See [EncryptedMem::decrypt_into_array] for the errors returned.
         pub fn decrypt_32byte(&self) -> MemSecurityResult<ZeroizeArray<32>> {
-            let mut kek = SEALING_KEY.kek();
-            let kek_ptr = kek.as_mut_ptr();
-            SEALING_KEY.mlock_kek(kek_ptr); //TODO Handle this bool
-
-            let cipher = Ascon128a::new(kek[0..16].as_ref().into());
-
-            let outcome =
-                match cipher.decrypt(&self.nonce, self.ciphertext.expose_borrowed().as_ref()) {
-                    Ok(plaintext) => {
-                        let plaintext_len = plaintext.len();
-                        if plaintext_len != crate::SECRET_KEY_32BYTE {
-                            return Err(MemSecurityErr::InvalidArrayLength {
-                                expected: crate::SECRET_KEY_32BYTE,
-                                found: plaintext_len,
-                            });
-                        } else {
-                            ZeroizeArray::<{ crate::SECRET_KEY_32BYTE }>::new_from_slice(&plaintext)
-                        }
-                    }
-                    Err(_) => Err(MemSecurityErr::EncryptionErr),
-                };
-
-            SEALING_KEY.munlock_kek(kek_ptr); //TODO Handle this bool
-
-            debug_assert_eq!(kek, [0u8; blake3::OUT_LEN]);
+            self.decrypt_into_array::<{ crate::SECRET_KEY_32BYTE }>()
+        }
 
-            outcome
+        /// Performs a decryption operation expecting a plaintext of `expected_len` bytes.
+        ///
+        /// The length of the plaintext is checked against the length of the ciphertext before the cipher is used
+        /// so [MemSecurityErr::UnexpectedPlaintextLength] does not reveal whether the tag was valid.
+        /// [MemSecurityErr::AuthenticationFailed] is returned if the tag does not match.
+        /// Use [EncryptedMem::decrypt_into_array] when the length is known at compile time.
+        pub fn verify_and_decrypt(&self, expected_len: usize) -> MemSecurityResult<ZeroizeBytes> {
+            let found = self
+                .ciphertext
+                .expose_borrowed()
+                .len()
+                .saturating_sub(crate::ASCON128_TAG_LEN);
+
+            if found != expected_len {
+                return Err(MemSecurityErr::UnexpectedPlaintextLength {
+                    expected: expected_len,
+                    found,
+                });
+            }
+
+            self.decrypt()
+        }
+
+        /// Performs a decryption operation expecting an `N` byte plaintext which is decrypted in place
+        /// inside the returned array, so the plaintext is never written to the heap.
+        ///
+        /// The length of the plaintext is checked against the length of the ciphertext before the cipher is used
+        /// so [MemSecurityErr::UnexpectedPlaintextLength] does not reveal whether the tag was valid.
+        /// [MemSecurityErr::AuthenticationFailed] is returned if the tag does not match, in which case
+        /// the partially decrypted array is zeroized.
+        pub fn decrypt_into_array<const N: usize>(&self) -> MemSecurityResult<ZeroizeArray<N>> {
+            let ciphertext = self.ciphertext.expose_borrowed();
+
+            if ciphertext.len() != N + crate::ASCON128_TAG_LEN {
+                return Err(MemSecurityErr::UnexpectedPlaintextLength {
+                    expected: N,
+                    found: ciphertext.len().saturating_sub(crate::ASCON128_TAG_LEN),
+                });
+            }
+
+            let (ciphertext, tag) = ciphertext.split_at(N);
+
+            let mut outcome = ZeroizeArray::<N>::new_from_slice(ciphertext)?;
+
+            let kek = KekGuard::new(self.context.as_deref());
+
+            let decrypted = kek.cipher().decrypt_in_place_detached(
+                self.ascon_nonce(),
+                &self.aad,
+                outcome.expose_borrowed_mut(),
+                tag.into(),
+            );
+
+            drop(kek);
+
+            // Wipe the buffer so no unauthenticated plaintext is left behind when the tag does not match
+            if decrypted.is_err() {
+                outcome.zeroize();
+
+                return Err(MemSecurityErr::AuthenticationFailed);
+            }
+
+            Ok(outcome)
         }
 
         /// Sign a message and return an Ed25519 digital signature
@@ -365,6 +2041,15 @@ mod key_ops {
         ) -> MemSecurityResult<ed25519_dalek::Signature> {
             use ed25519_dalek::{Signer, SigningKey};
 
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!(
+                "sign",
+                message_len = message.as_ref().len(),
+                ciphertext_fingerprint =
+                    tracing::field::display(blake3::hash(self.ciphertext.expose_borrowed()))
+            )
+            .entered();
+
             let encrypted_key = self.decrypt_32byte()?;
 
             let signing_key = SigningKey::from_bytes(encrypted_key.expose_borrowed());
@@ -374,6 +2059,107 @@ mod key_ops {
             Ok(signing_key.sign(message.as_ref()))
         }
 
+        /// Sign a message that was hashed incrementally with `blake3` so that large messages or streams never need
+        /// to be buffered in memory.
+        ///
+        /// This is a construction specific to this crate and not plain Ed25519ph (RFC 8032): the Ed25519ph
+        /// signature is computed over `SHA-512(prehash)` instead of the SHA-512 of the message, with
+        /// [crate::BLAKE3_PREHASH_SIGNATURE_LABEL] followed by `context` as the Ed25519ph context.
+        /// A verifier must reproduce both steps, [EncryptedMem::verify_prehashed] does so.
+        /// [MemSecurityErr::SignatureError] is returned if `context` is longer than
+        /// `255 - BLAKE3_PREHASH_SIGNATURE_LABEL.len()` bytes.
+        #[cfg(feature = "ed25519")]
+        pub fn sign_prehashed(
+            &self,
+            context: &[u8],
+            prehash: blake3::Hash,
+        ) -> MemSecurityResult<ed25519_dalek::Signature> {
+            use ed25519_dalek::SigningKey;
+
+            let encrypted_key = self.decrypt_32byte()?;
+
+            let signing_key = SigningKey::from_bytes(encrypted_key.expose_borrowed());
+
+            drop(encrypted_key);
+
+            signing_key
+                .sign_prehashed(
+                    Self::blake3_prehash_digest(&prehash),
+                    Some(&Self::blake3_prehash_context(context)),
+                )
+                .or(Err(MemSecurityErr::SignatureError))
+        }
+
+        /// Verify a signature created by [EncryptedMem::sign_prehashed] over the same `blake3` prehash
+        /// and `context`. Returns [MemSecurityErr::SignatureError] if the signature does not match.
+        #[cfg(feature = "ed25519")]
+        pub fn verify_prehashed(
+            verifying_key: &ed25519_dalek::VerifyingKey,
+            context: &[u8],
+            prehash: blake3::Hash,
+            signature: &ed25519_dalek::Signature,
+        ) -> MemSecurityResult<()> {
+            verifying_key
+                .verify_prehashed_strict(
+                    Self::blake3_prehash_digest(&prehash),
+                    Some(&Self::blake3_prehash_context(context)),
+                    signature,
+                )
+                .or(Err(MemSecurityErr::SignatureError))
+        }
+
+        /// The SHA-512 state handed to Ed25519ph, holding the `blake3` prehash
+        #[cfg(feature = "ed25519")]
+        fn blake3_prehash_digest(prehash: &blake3::Hash) -> ed25519_dalek::Sha512 {
+            use ed25519_dalek::Digest;
+
+            ed25519_dalek::Sha512::new().chain_update(prehash.as_bytes())
+        }
+
+        /// The Ed25519ph context, [crate::BLAKE3_PREHASH_SIGNATURE_LABEL] followed by the caller's `context`
+        #[cfg(feature = "ed25519")]
+        fn blake3_prehash_context(context: &[u8]) -> Vec<u8> {
+            [crate::BLAKE3_PREHASH_SIGNATURE_LABEL, context].concat()
+        }
+
+        /// Generate a new Ed25519 signing key from the CSPRNG, encrypt its 32 byte secret immediately
+        /// and return the public verifying key
+        #[cfg(feature = "ed25519")]
+        pub fn generate_ed25519(&mut self) -> MemSecurityResult<ed25519_dalek::VerifyingKey> {
+            use ed25519_dalek::SigningKey;
+
+            let secret = CsprngArray::<{ crate::SECRET_KEY_32BYTE }>::gen();
+            self.encrypt(&secret)?;
+
+            let mut secret_bytes = secret.expose();
+            drop(secret);
+
+            let signing_key = SigningKey::from_bytes(&secret_bytes);
+            secret_bytes.zeroize();
+
+            Ok(signing_key.verifying_key())
+        }
+
+        /// Generate a new X25519 static secret from the CSPRNG, encrypt its 32 byte secret immediately
+        /// and return the public key. The secret is clamped by `x25519_dalek` whenever it is used so
+        /// the raw random bytes are sealed as they are.
+        #[cfg(feature = "x25519")]
+        pub fn generate_x25519(&mut self) -> MemSecurityResult<x25519_dalek::PublicKey> {
+            use x25519_dalek::{PublicKey, StaticSecret};
+
+            let secret = CsprngArray::<{ crate::SECRET_KEY_32BYTE }>::gen();
+            self.encrypt(&secret)?;
+
+            let mut secret_bytes = [0u8; crate::SECRET_KEY_32BYTE];
+            secret_bytes.copy_from_slice(secret.expose_borrowed());
+            drop(secret);
+
+            let x25519_static_key = StaticSecret::from(secret_bytes);
+            secret_bytes.zeroize();
+
+            Ok(PublicKey::from(&x25519_static_key))
+        }
+
         /// Perform a Diffie-Hellman key exchange of a secret key
         /// assuming that that secret key was added as an X25519 static secret
         #[cfg(feature = "x25519")]
@@ -381,13 +2167,7 @@ mod key_ops {
             &self,
             x25519_public_key: x25519_dalek::PublicKey,
         ) -> MemSecurityResult<x25519_dalek::SharedSecret> {
-            use x25519_dalek::StaticSecret;
-
-            let encrypted_key = self.decrypt_32byte()?;
-
-            let x25519_static_key = StaticSecret::from(*encrypted_key.expose_borrowed());
-
-            drop(encrypted_key);
+            let x25519_static_key = self.x25519_static_secret()?;
 
             Ok(x25519_static_key.diffie_hellman(&x25519_public_key))
         }
@@ -395,15 +2175,28 @@ mod key_ops {
         /// Generate the public key assuming that that secret key was added as an X25519 static secret
         #[cfg(feature = "x25519")]
         pub fn x25519_public_key(&self) -> MemSecurityResult<x25519_dalek::PublicKey> {
-            use x25519_dalek::{PublicKey, StaticSecret};
+            use x25519_dalek::PublicKey;
+
+            let x25519_static_key = self.x25519_static_secret()?;
+
+            Ok(PublicKey::from(&x25519_static_key))
+        }
 
+        /// Decrypt the secret key into an X25519 static secret. The bytes are copied into a local
+        /// array that is wiped once the [x25519_dalek::StaticSecret], which zeroizes itself on drop, holds them.
+        #[cfg(feature = "x25519")]
+        fn x25519_static_secret(&self) -> MemSecurityResult<x25519_dalek::StaticSecret> {
             let encrypted_key = self.decrypt_32byte()?;
 
-            let x25519_static_key = StaticSecret::from(*encrypted_key.expose_borrowed());
+            let mut secret_bytes = [0u8; crate::SECRET_KEY_32BYTE];
+            secret_bytes.copy_from_slice(encrypted_key.expose_borrowed());
 
             drop(encrypted_key);
 
-            Ok(PublicKey::from(&x25519_static_key))
+            let x25519_static_key = x25519_dalek::StaticSecret::from(secret_bytes);
+            secret_bytes.zeroize();
+
+            Ok(x25519_static_key)
         }
 
         /// Generate a new version 4 UUID and encrypt it immediately
@@ -4,12 +4,73 @@ pub type MemSecurityResult<T> = Result<T, MemSecurityErr>;
 /// Errors encountered in execution of the code in this crate
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum MemSecurityErr {
-    /// An error was encountered while encrypting the data using Ascon128a  
+    /// The cipher was unable to encrypt the plaintext using Ascon128a
     #[cfg(feature = "encryption")]
-    EncryptionErr,
-    /// An error was encountered when decrypting data using Ascon128a    
+    EncryptionFailed,
+    /// The authentication tag of the ciphertext did not match, it was tampered with
+    /// or sealed under a different key, nonce or context.
+    /// Every decryption failure is reported with this variant, which replaces `DecryptionError`
     #[cfg(feature = "encryption")]
-    DecryptionError,
+    AuthenticationFailed,
+    /// The ciphertext does not hold a plaintext of the length the typed decryption expects
+    #[cfg(feature = "encryption")]
+    UnexpectedPlaintextLength {
+        /// The length of the plaintext that was expected
+        expected: usize,
+        /// The length of the plaintext the ciphertext holds
+        found: usize,
+    },
+    /// The ciphertext is too short to hold the authentication tag, for example because a stored value was truncated
+    #[cfg(feature = "encryption")]
+    CiphertextTooShort {
+        /// The minimum length of the ciphertext
+        min: usize,
+        /// The length of the ciphertext that was provided
+        found: usize,
+    },
+    /// A value created with a counter based nonce was encrypted again, which needs the next counter
+    /// from the caller rather than a random nonce
+    #[cfg(feature = "encryption")]
+    CounterNonceReused,
+    /// The serialized sealed value has a version this build does not understand
+    #[cfg(feature = "encryption")]
+    UnsupportedVersion {
+        /// The version byte that was found
+        found: u8,
+    },
+    /// The operating system refused to `mlock` the memory region
+    #[cfg(feature = "encryption")]
+    MlockError,
+    /// The sealing key was already initialized, either by an earlier seed or by its first use
+    #[cfg(feature = "encryption")]
+    SealingKeyAlreadyInitialized,
+    /// The seed used to initialize the sealing key is too short
+    #[cfg(feature = "encryption")]
+    SeedTooShort {
+        /// The minimum length of the seed
+        min: usize,
+        /// The length of the seed that was provided
+        found: usize,
+    },
+    /// The padding block size must be between 1 and 255 bytes
+    #[cfg(feature = "encryption")]
+    InvalidPaddingBlock {
+        /// The block size that was provided
+        block: usize,
+    },
+    /// The authenticated plaintext does not end with valid padding for the block size
+    #[cfg(feature = "encryption")]
+    InvalidPadding,
+    /// The envelope is not unpadded base64url, has an unknown version or is too short to hold a nonce and a tag
+    #[cfg(feature = "envelope")]
+    InvalidEnvelope,
+    /// An error was encountered while stretching a password using Argon2id
+    #[cfg(feature = "argon2")]
+    PasswordDerivationError,
+    /// A prehashed Ed25519 signature could not be created or did not verify, for example because the context
+    /// is too long or the signature does not match the prehash
+    #[cfg(feature = "ed25519")]
+    SignatureError,
     /// The length of the arrays should be the same
     InvalidArrayLength {
         /// The length defined in generic value `N` in `const N: usize`
@@ -24,4 +85,131 @@ pub enum MemSecurityErr {
         /// The length of the `&[u8]` slice
         found: usize,
     },
+    /// The index is outside the bounds of the array
+    IndexOutOfBounds {
+        /// The index that was accessed
+        index: usize,
+        /// The length of the array
+        len: usize,
+    },
+    /// The range to draw a random value from is empty or one of its bounds is not a finite number
+    #[cfg(feature = "random")]
+    InvalidRange,
+    /// The entropy source of the operating system failed so the CSPRNG could not be seeded
+    #[cfg(feature = "random")]
+    RngFailure,
+    /// The collection is full and cannot hold another element
+    CapacityExceeded {
+        /// The maximum number of elements the collection can hold
+        capacity: usize,
+    },
+    /// The counter would wrap around past its maximum value
+    CounterOverflow,
+    /// An IO error was encountered while reading or writing secrets.
+    /// Only the [std::io::ErrorKind] is stored which keeps the error cheap to copy and compare.
+    #[cfg(feature = "symm_asymm")]
+    Io(std::io::ErrorKind),
+}
+
+#[cfg(feature = "symm_asymm")]
+impl From<std::io::Error> for MemSecurityErr {
+    fn from(error: std::io::Error) -> Self {
+        MemSecurityErr::Io(error.kind())
+    }
 }
+
+impl core::fmt::Display for MemSecurityErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::EncryptionFailed => write!(f, "Unable to encrypt the plaintext"),
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::AuthenticationFailed => {
+                write!(f, "The ciphertext could not be authenticated")
+            }
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::UnexpectedPlaintextLength { expected, found } => write!(
+                f,
+                "Expected a plaintext of `{expected}` bytes but the ciphertext holds `{found}` bytes"
+            ),
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::CiphertextTooShort { min, found } => write!(
+                f,
+                "The ciphertext must be at least `{min}` bytes but found `{found}` bytes"
+            ),
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::CounterNonceReused => write!(
+                f,
+                "The counter based nonce has already been used, create a new value with the next counter"
+            ),
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::UnsupportedVersion { found } => {
+                write!(f, "The sealed value has the unsupported version `{found}`")
+            }
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::MlockError => {
+                write!(f, "The operating system refused to lock the memory")
+            }
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::SealingKeyAlreadyInitialized => {
+                write!(f, "The sealing key has already been initialized")
+            }
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::SeedTooShort { min, found } => write!(
+                f,
+                "The seed must be at least `{min}` bytes but found `{found}` bytes"
+            ),
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::InvalidPaddingBlock { block } => write!(
+                f,
+                "The padding block size must be between 1 and 255 bytes but found `{block}` bytes"
+            ),
+            #[cfg(feature = "encryption")]
+            MemSecurityErr::InvalidPadding => write!(f, "The plaintext is not correctly padded"),
+            #[cfg(feature = "envelope")]
+            MemSecurityErr::InvalidEnvelope => write!(
+                f,
+                "The envelope is malformed, truncated or has an unknown version"
+            ),
+            #[cfg(feature = "argon2")]
+            MemSecurityErr::PasswordDerivationError => {
+                write!(f, "Unable to derive a key from the password using Argon2id")
+            }
+            #[cfg(feature = "ed25519")]
+            MemSecurityErr::SignatureError => {
+                write!(f, "Unable to create or verify the prehashed Ed25519 signature")
+            }
+            MemSecurityErr::InvalidArrayLength { expected, found } => write!(
+                f,
+                "Invalid array length, expected `{expected}` bytes but found `{found}` bytes"
+            ),
+            MemSecurityErr::InvalidSliceLength { expected, found } => write!(
+                f,
+                "Invalid slice length, expected `{expected}` bytes but found `{found}` bytes"
+            ),
+            MemSecurityErr::IndexOutOfBounds { index, len } => write!(
+                f,
+                "The index `{index}` is out of bounds for an array of length `{len}`"
+            ),
+            #[cfg(feature = "random")]
+            MemSecurityErr::InvalidRange => {
+                write!(f, "The range to draw a random value from is empty")
+            }
+            #[cfg(feature = "random")]
+            MemSecurityErr::RngFailure => {
+                write!(f, "The entropy source of the operating system failed")
+            }
+            MemSecurityErr::CapacityExceeded { capacity } => write!(
+                f,
+                "The collection is full, it can only hold `{capacity}` elements"
+            ),
+            MemSecurityErr::CounterOverflow => {
+                write!(f, "The counter would wrap around past its maximum value")
+            }
+            #[cfg(feature = "symm_asymm")]
+            MemSecurityErr::Io(kind) => write!(f, "IO error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for MemSecurityErr {}
@@ -15,6 +15,11 @@ mod encrypted_mem;
 #[cfg(feature = "encryption")]
 pub use encrypted_mem::*;
 
+#[cfg(feature = "encryption")]
+mod sealed_io;
+#[cfg(feature = "encryption")]
+pub use sealed_io::*;
+
 #[cfg(feature = "symm_asymm")]
 mod zeroizable_arrays;
 #[cfg(feature = "symm_asymm")]
@@ -30,14 +35,20 @@ mod traits;
 #[cfg(feature = "symm_asymm")]
 pub use traits::*;
 
-/// Re-export  crates
-#[cfg(feature = "encryption")]
+// Re-exported crates, each gated on the feature that depends on it
+#[cfg(feature = "atomic_sealed")]
+pub use arc_swap;
+#[cfg(feature = "argon2")]
+pub use argon2;
+#[cfg(feature = "symm_asymm")]
 pub use arrayvec;
 #[cfg(feature = "encryption")]
 pub use ascon_aead;
-#[cfg(feature = "encryption")]
+#[cfg(feature = "envelope")]
+pub use base64ct;
+#[cfg(feature = "symm_asymm")]
 pub use blake3;
-#[cfg(feature = "encryption")]
+#[cfg(feature = "symm_asymm")]
 pub use bytes;
 #[cfg(feature = "ed25519")]
 pub use ed25519_dalek;
@@ -49,6 +60,12 @@ pub use once_cell;
 pub use rand_chacha;
 #[cfg(feature = "random")]
 pub use rand_core;
+#[cfg(feature = "secrecy")]
+pub use secrecy;
+#[cfg(feature = "serde")]
+pub use serde;
+#[cfg(feature = "symm_asymm")]
+pub use subtle;
 #[cfg(feature = "uuid")]
 pub use uuid;
 #[cfg(feature = "x25519")]
@@ -72,6 +72,74 @@ impl CsprngArraySimple {
     pub fn gen_u64_array() -> CsprngArray<64> {
         CsprngArray::<64>::gen()
     }
+
+    /// Generate a random `u32`
+    ///
+    /// #### Usage
+    /// ```rs
+    /// let value = CsprngArraySimple::gen_u32();
+    /// ```
+    pub fn gen_u32() -> u32 {
+        u32::from_le_bytes(CsprngArray::<4>::gen().expose())
+    }
+
+    /// Generate a random `u64`
+    ///
+    /// #### Usage
+    /// ```rs
+    /// let value = CsprngArraySimple::gen_u64();
+    /// ```
+    pub fn gen_u64() -> u64 {
+        u64::from_le_bytes(CsprngArray::<8>::gen().expose())
+    }
+
+    /// Generate a random value uniformly distributed in `[min, max]` for integers or `[min, max)` for floats.
+    /// Rejection sampling is used so the value is not biased towards the start of the range.
+    ///
+    /// #### Panics
+    /// Panics if the range is empty or a float bound is not finite.
+    ///
+    /// #### Usage
+    /// ```rs
+    /// let dice = CsprngArraySimple::gen_range(1u8, 6u8);
+    /// assert!((1..=6).contains(&dice));
+    /// ```
+    pub fn gen_range<T: MinMaxNum>(min: T, max: T) -> T {
+        let mut rng = MixedRng(ChaCha20Rng::from_entropy());
+
+        T::sample_uniform(&mut rng, min, max)
+            .expect("The range to draw a random value from is empty")
+    }
+
+    /// Generate a value spanning the whole range of `T` from [MinMaxNum::MIN_VALUE] to [MinMaxNum::MAX_VALUE].
+    /// Floats are drawn uniformly over the finite range `[MIN_VALUE, MAX_VALUE)`.
+    ///
+    /// #### Usage
+    /// ```rs
+    /// let value = CsprngArraySimple::gen_bounded::<u32>();
+    /// ```
+    pub fn gen_bounded<T: MinMaxNum>() -> T {
+        let mut rng = MixedRng(ChaCha20Rng::from_entropy());
+
+        T::sample_uniform(&mut rng, T::MIN_VALUE, T::MAX_VALUE)
+            .expect("The full range of a numeric type is never empty")
+    }
+
+    /// Generate a value uniformly distributed in `[lo, hi]` for integers or `[lo, hi)` for floats
+    /// using rejection sampling to avoid modulo bias. Returns [crate::MemSecurityErr::InvalidRange]
+    /// if the range is empty or a float bound is not finite and [crate::MemSecurityErr::RngFailure]
+    /// if the entropy source of the operating system fails.
+    ///
+    /// #### Usage
+    /// ```rs
+    /// let value = CsprngArraySimple::gen_in_range(10u16, 20u16)?;
+    /// assert!((10..=20).contains(&value));
+    /// ```
+    pub fn gen_in_range<T: MinMaxNum>(lo: T, hi: T) -> MemSecurityResult<T> {
+        let mut rng = MixedRng(try_seeded_rng()?);
+
+        T::sample_uniform(&mut rng, lo, hi).ok_or(crate::MemSecurityErr::InvalidRange)
+    }
 }
 
 /// Generate Cryptographically secure random bytes of different sizes based on generic usize `N`
@@ -105,6 +173,8 @@ impl<const N: usize> CsprngArray<N> {
         let mut rng = ChaCha20Rng::from_entropy();
         let mut buffer = [0u8; N];
         rng.fill_bytes(&mut buffer);
+        #[cfg(feature = "hwrng_mix")]
+        mix_hardware_rng(&mut buffer);
 
         let outcome = CsprngArray(buffer);
 
@@ -113,6 +183,27 @@ impl<const N: usize> CsprngArray<N> {
         outcome
     }
 
+    /// Generate cryptographically secure random bytes returning [crate::MemSecurityErr::RngFailure]
+    /// instead of panicking if the entropy source of the operating system fails
+    /// #### Example
+    /// ```rs
+    /// let bytes = CsprngArray::<32>::try_gen()?;
+    /// assert_eq!(bytes.len(), 32);
+    /// ```
+    pub fn try_gen() -> MemSecurityResult<Self> {
+        let mut rng = try_seeded_rng()?;
+        let mut buffer = [0u8; N];
+        rng.fill_bytes(&mut buffer);
+        #[cfg(feature = "hwrng_mix")]
+        mix_hardware_rng(&mut buffer);
+
+        let outcome = CsprngArray(buffer);
+
+        buffer.fill(0);
+
+        Ok(outcome)
+    }
+
     /// Copies the contents of the buffer
     pub fn take(mut self, buffer: &mut [u8; N]) -> MemSecurityResult<()> {
         // FIXME implement
@@ -166,6 +257,118 @@ impl<const N: usize> CsprngArray<N> {
     }
 }
 
+/// Seed a [ChaCha20Rng] from the entropy source of the operating system, returning an error instead of
+/// panicking like [SeedableRng::from_entropy] does if the entropy source fails. The seed is wiped after use.
+fn try_seeded_rng() -> MemSecurityResult<ChaCha20Rng> {
+    let mut seed = <ChaCha20Rng as SeedableRng>::Seed::default();
+
+    if rand_core::OsRng.try_fill_bytes(&mut seed).is_err() {
+        return Err(crate::MemSecurityErr::RngFailure);
+    }
+
+    let rng = ChaCha20Rng::from_seed(seed);
+    seed.zeroize();
+
+    Ok(rng)
+}
+
+/// The [ChaCha20Rng] the range draws sample from. With the `hwrng_mix` feature every output is mixed
+/// using `mix_hardware_rng` like the output of [CsprngArray::gen].
+struct MixedRng(ChaCha20Rng);
+
+impl RngCore for MixedRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+        #[cfg(feature = "hwrng_mix")]
+        mix_hardware_rng(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+/// XOR the output of the `RDSEED` or `RDRAND` instruction into `buffer`, which already holds the output
+/// of [ChaCha20Rng], so a flaw in either source alone does not make the bytes predictable.
+/// The instructions are detected at runtime and nothing is mixed in on other CPUs or architectures.
+/// A word the hardware fails to deliver after a few retries is left as zeroes, which leaves those bytes unchanged.
+#[cfg(feature = "hwrng_mix")]
+pub(crate) fn mix_hardware_rng(buffer: &mut [u8]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        let draw: fn() -> Option<u64> = if std::arch::is_x86_feature_detected!("rdseed") {
+            hardware_rng::rdseed_word
+        } else if std::arch::is_x86_feature_detected!("rdrand") {
+            hardware_rng::rdrand_word
+        } else {
+            return;
+        };
+
+        buffer.chunks_mut(8).for_each(|chunk| {
+            let mut word = draw().unwrap_or_default().to_ne_bytes();
+
+            chunk
+                .iter_mut()
+                .zip(word.iter())
+                .for_each(|(byte, hardware_byte)| *byte ^= hardware_byte);
+
+            word.zeroize();
+        });
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = buffer;
+}
+
+#[cfg(all(feature = "hwrng_mix", target_arch = "x86_64"))]
+#[allow(unsafe_code)]
+mod hardware_rng {
+    use core::arch::x86_64::{_rdrand64_step, _rdseed64_step};
+
+    /// The number of times a draw is retried since both instructions may fail transiently under contention
+    const HARDWARE_RNG_RETRIES: usize = 10;
+
+    /// Draw a word using `RDSEED`, must only be called after `rdseed` was detected
+    pub(super) fn rdseed_word() -> Option<u64> {
+        let mut word = 0u64;
+
+        // SAFETY: the caller has detected the `rdseed` target feature at runtime
+        (0..HARDWARE_RNG_RETRIES)
+            .any(|_| unsafe { rdseed64(&mut word) } == 1)
+            .then_some(word)
+    }
+
+    /// Draw a word using `RDRAND`, must only be called after `rdrand` was detected
+    pub(super) fn rdrand_word() -> Option<u64> {
+        let mut word = 0u64;
+
+        // SAFETY: the caller has detected the `rdrand` target feature at runtime
+        (0..HARDWARE_RNG_RETRIES)
+            .any(|_| unsafe { rdrand64(&mut word) } == 1)
+            .then_some(word)
+    }
+
+    #[target_feature(enable = "rdseed")]
+    unsafe fn rdseed64(word: &mut u64) -> i32 {
+        _rdseed64_step(word)
+    }
+
+    #[target_feature(enable = "rdrand")]
+    unsafe fn rdrand64(word: &mut u64) -> i32 {
+        _rdrand64_step(word)
+    }
+}
+
 impl<const N: usize> Zeroize for CsprngArray<N> {
     fn zeroize(&mut self) {
         self.0.fill(0);
@@ -211,64 +414,99 @@ pub trait MinMaxNum: PartialOrd + Add + Sub + Copy {
     const MIN_VALUE: Self;
     /// The maximum value that can be defined
     const MAX_VALUE: Self;
-}
 
-impl MinMaxNum for u8 {
-    const MIN_VALUE: u8 = core::u8::MIN;
-    const MAX_VALUE: u8 = core::u8::MAX;
+    /// Draw a value from `rng` that is uniformly distributed in `[low, high]` for integers
+    /// or in `[low, high)` for floats. Rejection sampling is used so that the value is unbiased.
+    /// Returns `None` if the range is empty or a bound is not a finite number.
+    fn sample_uniform<R: RngCore>(rng: &mut R, low: Self, high: Self) -> Option<Self>;
 }
 
-impl MinMaxNum for u16 {
-    const MIN_VALUE: u16 = core::u16::MIN;
-    const MAX_VALUE: u16 = core::u16::MAX;
-}
+/// Draw a value uniformly distributed in `[0, max]`. Draws that fall in the incomplete
+/// last multiple of the range are rejected so that taking the remainder is not biased.
+fn uniform_u128<R: RngCore>(rng: &mut R, max: u128) -> u128 {
+    let mut draw = || {
+        let mut buffer = [0u8; 16];
+        rng.fill_bytes(&mut buffer);
+        let value = u128::from_le_bytes(buffer);
+        buffer.zeroize();
 
-impl MinMaxNum for u32 {
-    const MIN_VALUE: u32 = core::u32::MIN;
-    const MAX_VALUE: u32 = core::u32::MAX;
-}
+        value
+    };
 
-impl MinMaxNum for u64 {
-    const MIN_VALUE: u64 = core::u64::MIN;
-    const MAX_VALUE: u64 = core::u64::MAX;
-}
+    if max == u128::MAX {
+        return draw();
+    }
 
-impl MinMaxNum for u128 {
-    const MIN_VALUE: u128 = core::u128::MIN;
-    const MAX_VALUE: u128 = core::u128::MAX;
-}
+    let range = max + 1;
+    // `2^128 % range`, every draw below `2^128 - rejected` maps onto the range evenly
+    let rejected = (u128::MAX % range + 1) % range;
 
-impl MinMaxNum for f32 {
-    const MIN_VALUE: f32 = core::f32::MIN;
-    const MAX_VALUE: f32 = core::f32::MAX;
-}
+    loop {
+        let value = draw();
 
-impl MinMaxNum for f64 {
-    const MIN_VALUE: f64 = core::f64::MIN;
-    const MAX_VALUE: f64 = core::f64::MAX;
+        if value <= u128::MAX - rejected {
+            return value % range;
+        }
+    }
 }
 
-impl MinMaxNum for i8 {
-    const MIN_VALUE: i8 = core::i8::MIN;
-    const MAX_VALUE: i8 = core::i8::MAX;
-}
+macro_rules! impl_min_max_int {
+    ($int:ty, $unsigned:ty) => {
+        impl MinMaxNum for $int {
+            const MIN_VALUE: $int = <$int>::MIN;
+            const MAX_VALUE: $int = <$int>::MAX;
 
-impl MinMaxNum for i16 {
-    const MIN_VALUE: i16 = core::i16::MIN;
-    const MAX_VALUE: i16 = core::i16::MAX;
-}
+            fn sample_uniform<R: RngCore>(rng: &mut R, low: Self, high: Self) -> Option<Self> {
+                if low > high {
+                    return None;
+                }
 
-impl MinMaxNum for i32 {
-    const MIN_VALUE: i32 = core::i32::MIN;
-    const MAX_VALUE: i32 = core::i32::MAX;
-}
+                let span = high.wrapping_sub(low) as $unsigned;
+                let offset = uniform_u128(rng, span as u128) as $unsigned;
 
-impl MinMaxNum for i64 {
-    const MIN_VALUE: i64 = core::i64::MIN;
-    const MAX_VALUE: i64 = core::i64::MAX;
+                Some(low.wrapping_add(offset as $int))
+            }
+        }
+    };
 }
 
-impl MinMaxNum for i128 {
-    const MIN_VALUE: i128 = core::i128::MIN;
-    const MAX_VALUE: i128 = core::i128::MAX;
+macro_rules! impl_min_max_float {
+    ($float:ty, $mantissa_bits:expr) => {
+        impl MinMaxNum for $float {
+            const MIN_VALUE: $float = <$float>::MIN;
+            const MAX_VALUE: $float = <$float>::MAX;
+
+            fn sample_uniform<R: RngCore>(rng: &mut R, low: Self, high: Self) -> Option<Self> {
+                if !low.is_finite() || !high.is_finite() || low >= high {
+                    return None;
+                }
+
+                loop {
+                    let fraction = uniform_u128(rng, (1u128 << $mantissa_bits) - 1) as $float
+                        / (1u128 << $mantissa_bits) as $float;
+                    // Interpolating instead of `low + (high - low) * fraction` avoids overflowing
+                    // to infinity when the range spans more than the largest finite value
+                    let value = low * (1.0 - fraction) + high * fraction;
+
+                    // Rounding can land exactly on `high` which is outside the range
+                    if value >= low && value < high {
+                        return Some(value);
+                    }
+                }
+            }
+        }
+    };
 }
+
+impl_min_max_int!(u8, u8);
+impl_min_max_int!(u16, u16);
+impl_min_max_int!(u32, u32);
+impl_min_max_int!(u64, u64);
+impl_min_max_int!(u128, u128);
+impl_min_max_int!(i8, u8);
+impl_min_max_int!(i16, u16);
+impl_min_max_int!(i32, u32);
+impl_min_max_int!(i64, u64);
+impl_min_max_int!(i128, u128);
+impl_min_max_float!(f32, f32::MANTISSA_DIGITS);
+impl_min_max_float!(f64, f64::MANTISSA_DIGITS);
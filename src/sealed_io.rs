@@ -0,0 +1,385 @@
+//! This module contains adapters over `std::io` that encrypt data flowing through an [io::Write]
+//! and decrypt data flowing out of an [io::Read] using the sealing key of this process.
+//! A stream starts with a random [SEALED_STREAM_ID_LEN] byte stream identifier, followed by the frames.
+//! Each chunk of plaintext is sealed by an [EncryptedMem] with a fresh nonce and written as a frame
+//! with the layout `nonce || ciphertext length (u32 little-endian) || final flag (u8) || ciphertext`.
+//! The associated data of every frame is `stream identifier || frame index (u64 little-endian) || final flag`
+//! so frames cannot be reordered, duplicated, dropped or moved between streams, and a stream that does not
+//! end with its final frame is rejected as truncated.
+//! Since the sealing key only lives in memory, sealed frames can only be opened by the same process,
+//! which makes these adapters suitable for spilling secrets to pipes or temporary files.
+
+use crate::{
+    CsprngArray, EncryptedMem, EncryptedMemBuilder, ZeroizeBytes, ASCON128_NONCE_LEN,
+    ASCON128_TAG_LEN,
+};
+use std::io::{self, Read, Write};
+use zeroize::Zeroize;
+
+/// The default number of plaintext bytes buffered before a chunk is sealed
+pub const DEFAULT_SEALED_CHUNK_SIZE: usize = 4096;
+/// The length of the random identifier written at the start of every sealed stream
+pub const SEALED_STREAM_ID_LEN: usize = 16;
+/// The length of the header preceding the ciphertext of every sealed frame
+pub const SEALED_FRAME_HEADER_LEN: usize = ASCON128_NONCE_LEN + core::mem::size_of::<u32>() + 1;
+
+/// Build the associated data binding a frame to its stream, its position and whether it ends the stream
+fn frame_aad(stream_id: &[u8; SEALED_STREAM_ID_LEN], frame_index: u64, last: bool) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(SEALED_STREAM_ID_LEN + core::mem::size_of::<u64>() + 1);
+    aad.extend_from_slice(stream_id);
+    aad.extend_from_slice(&frame_index.to_le_bytes());
+    aad.push(last as u8);
+
+    aad
+}
+
+/// Buffers plaintext into a zeroizing buffer and writes it to the inner writer as sealed frames.
+/// A frame is sealed whenever the buffer reaches the chunk size or when [SealedWriter::flush] is called.
+/// [SealedWriter::finish] must be called to write the final frame, a stream whose writer is dropped
+/// without finishing is rejected by [SealedReader] as truncated.
+/// The plaintext buffer is wiped after every frame is sealed and when the writer is dropped,
+/// any plaintext that was not flushed is discarded.
+/// #### Structure
+/// ```rs
+/// pub struct SealedWriter<W: Write> {
+///     inner: W,
+///     buffer: ZeroizeBytes,
+///     chunk_size: usize,
+///     stream_id: [u8; SEALED_STREAM_ID_LEN],
+///     frame_index: u64,
+/// }
+/// ```
+pub struct SealedWriter<W: Write> {
+    inner: W,
+    buffer: ZeroizeBytes,
+    chunk_size: usize,
+    stream_id: [u8; SEALED_STREAM_ID_LEN],
+    frame_index: u64,
+}
+
+impl<W: Write> SealedWriter<W> {
+    /// Initialize a [SealedWriter] sealing chunks of [DEFAULT_SEALED_CHUNK_SIZE] bytes
+    pub fn new(inner: W) -> Self {
+        Self::with_chunk_size(inner, DEFAULT_SEALED_CHUNK_SIZE)
+    }
+
+    /// Initialize a [SealedWriter] sealing chunks of `chunk_size` bytes. A `chunk_size` of zero is treated as one.
+    pub fn with_chunk_size(inner: W, chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.max(1);
+
+        SealedWriter {
+            inner,
+            buffer: ZeroizeBytes::new_with_capacity(chunk_size),
+            chunk_size,
+            stream_id: CsprngArray::<SEALED_STREAM_ID_LEN>::gen().expose(),
+            frame_index: 0,
+        }
+    }
+
+    /// Seal any buffered plaintext as the final frame of the stream and flush the inner writer.
+    /// Pass `&mut W` to [SealedWriter::new] to keep using the writer afterwards.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.seal_chunk(true)?;
+
+        self.inner.flush()
+    }
+
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Seal the buffered plaintext as the next frame. Only the final frame may be empty.
+    fn seal_chunk(&mut self, last: bool) -> io::Result<()> {
+        if !last && self.buffer.expose_borrowed().is_empty() {
+            return Ok(());
+        }
+
+        let next_index = match self.frame_index.checked_add(1) {
+            Some(next_index) => next_index,
+            None => {
+                self.buffer.clear_and_set(&[]);
+
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "The sealed stream has run out of frame indices",
+                ));
+            }
+        };
+
+        let aad = frame_aad(&self.stream_id, self.frame_index, last);
+        let outcome = EncryptedMemBuilder::new()
+            .aad(&aad)
+            .build()
+            .and_then(|mut sealed| {
+                sealed.encrypt(&self.buffer)?;
+
+                Ok(sealed)
+            });
+
+        // The plaintext is wiped whether sealing succeeded or not
+        self.buffer.clear_and_set(&[]);
+
+        let sealed = match outcome {
+            Ok(sealed) => sealed,
+            Err(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Unable to seal the buffered plaintext",
+                ))
+            }
+        };
+
+        let ciphertext = sealed.ciphertext().expose_borrowed();
+        let ciphertext_len = match u32::try_from(ciphertext.len()) {
+            Ok(ciphertext_len) => ciphertext_len,
+            Err(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "The sealed chunk is too large to be framed",
+                ))
+            }
+        };
+
+        if self.frame_index == 0 {
+            self.inner.write_all(&self.stream_id)?;
+        }
+
+        self.inner.write_all(sealed.nonce().expose_borrowed())?;
+        self.inner.write_all(&ciphertext_len.to_le_bytes())?;
+        self.inner.write_all(&[last as u8])?;
+        self.inner.write_all(ciphertext)?;
+
+        self.frame_index = next_index;
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for SealedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let room = self.chunk_size - self.buffer.expose_borrowed().len();
+        let written = room.min(buf.len());
+
+        self.buffer.extend_from_slice(&buf[..written]);
+
+        if self.buffer.expose_borrowed().len() == self.chunk_size {
+            self.seal_chunk(false)?;
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.seal_chunk(false)?;
+
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for SealedWriter<W> {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
+/// Reads sealed frames produced by a [SealedWriter] from the inner reader and decrypts them.
+/// Reading fails with [io::ErrorKind::InvalidData] if a frame was tampered with, reordered or replayed,
+/// or if data follows the final frame, and with [io::ErrorKind::UnexpectedEof] if the stream ends
+/// before its final frame. Frames longer than the chunk size of the reader are rejected before
+/// their ciphertext is read, so the reader must be configured with a chunk size at least as large as the writer's.
+/// The plaintext of the current frame is held in a zeroizing buffer which is wiped once it
+/// has been read completely and when the reader is dropped. Bytes copied into the caller's
+/// buffer are outside the zeroization guarantees of this crate.
+/// #### Structure
+/// ```rs
+/// pub struct SealedReader<R: Read> {
+///     inner: R,
+///     plaintext: ZeroizeBytes,
+///     position: usize,
+///     chunk_size: usize,
+///     stream_id: Option<[u8; SEALED_STREAM_ID_LEN]>,
+///     frame_index: u64,
+///     finished: bool,
+/// }
+/// ```
+pub struct SealedReader<R: Read> {
+    inner: R,
+    plaintext: ZeroizeBytes,
+    position: usize,
+    chunk_size: usize,
+    stream_id: Option<[u8; SEALED_STREAM_ID_LEN]>,
+    frame_index: u64,
+    finished: bool,
+}
+
+impl<R: Read> SealedReader<R> {
+    /// Initialize a [SealedReader] accepting frames of up to [DEFAULT_SEALED_CHUNK_SIZE] plaintext bytes
+    pub fn new(inner: R) -> Self {
+        Self::with_chunk_size(inner, DEFAULT_SEALED_CHUNK_SIZE)
+    }
+
+    /// Initialize a [SealedReader] accepting frames of up to `chunk_size` plaintext bytes.
+    /// A `chunk_size` of zero is treated as one.
+    pub fn with_chunk_size(inner: R, chunk_size: usize) -> Self {
+        SealedReader {
+            inner,
+            plaintext: ZeroizeBytes::new(),
+            position: 0,
+            chunk_size: chunk_size.max(1),
+            stream_id: None,
+            frame_index: 0,
+            finished: false,
+        }
+    }
+
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Read into `buf` until it is full or the inner reader ends, returning the number of bytes read
+    fn fill(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut filled = 0usize;
+
+        while filled < buf.len() {
+            match self.inner.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(filled)
+    }
+
+    fn truncated() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "The sealed stream ended before its final frame",
+        )
+    }
+
+    fn invalid(message: &'static str) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, message)
+    }
+
+    /// Read and decrypt the next frame, returning `false` once the inner reader ends after the final frame
+    fn open_frame(&mut self) -> io::Result<bool> {
+        self.plaintext.clear_and_set(&[]);
+        self.position = 0;
+
+        let stream_id = match self.stream_id {
+            Some(stream_id) => stream_id,
+            None => {
+                let mut stream_id = [0u8; SEALED_STREAM_ID_LEN];
+
+                if self.fill(&mut stream_id)? != SEALED_STREAM_ID_LEN {
+                    return Err(Self::truncated());
+                }
+                self.stream_id = Some(stream_id);
+
+                stream_id
+            }
+        };
+
+        let mut header = [0u8; SEALED_FRAME_HEADER_LEN];
+        let filled = self.fill(&mut header)?;
+
+        if self.finished {
+            return match filled {
+                0 => Ok(false),
+                _ => Err(Self::invalid("Data follows the final sealed frame")),
+            };
+        }
+
+        if filled != SEALED_FRAME_HEADER_LEN {
+            return Err(Self::truncated());
+        }
+
+        let mut ciphertext_len = [0u8; 4];
+        ciphertext_len.copy_from_slice(&header[ASCON128_NONCE_LEN..SEALED_FRAME_HEADER_LEN - 1]);
+        let ciphertext_len = u32::from_le_bytes(ciphertext_len) as usize;
+
+        let last = match header[SEALED_FRAME_HEADER_LEN - 1] {
+            0 => false,
+            1 => true,
+            _ => return Err(Self::invalid("The sealed frame has an invalid final flag")),
+        };
+
+        if ciphertext_len < ASCON128_TAG_LEN {
+            return Err(Self::invalid(
+                "The sealed frame is shorter than the authentication tag",
+            ));
+        }
+
+        // The length is not authenticated yet so it is bounded before anything is allocated
+        if ciphertext_len > self.chunk_size.saturating_add(ASCON128_TAG_LEN) {
+            return Err(Self::invalid(
+                "The sealed frame is larger than the chunk size of the reader",
+            ));
+        }
+
+        let mut ciphertext = vec![0u8; ciphertext_len];
+        if self.fill(&mut ciphertext)? != ciphertext_len {
+            return Err(Self::truncated());
+        }
+
+        let next_index = self
+            .frame_index
+            .checked_add(1)
+            .ok_or_else(|| Self::invalid("The sealed stream has run out of frame indices"))?;
+
+        let aad = frame_aad(&stream_id, self.frame_index, last);
+        let sealed = match EncryptedMem::from_parts(
+            &header[..ASCON128_NONCE_LEN],
+            ZeroizeBytes::new_with_data(&ciphertext),
+        ) {
+            Ok(sealed) => sealed.with_aad(&aad),
+            Err(_) => return Err(Self::invalid("The sealed frame has an invalid nonce")),
+        };
+
+        match sealed.decrypt() {
+            Ok(plaintext) => self.plaintext = plaintext,
+            Err(_) => return Err(Self::invalid("Unable to open the sealed frame")),
+        }
+
+        self.frame_index = next_index;
+        self.finished = last;
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> Read for SealedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.position == self.plaintext.expose_borrowed().len() {
+            if !self.open_frame()? {
+                return Ok(0);
+            }
+        }
+
+        let remaining = &self.plaintext.expose_borrowed()[self.position..];
+        let read = remaining.len().min(buf.len());
+        buf[..read].copy_from_slice(&remaining[..read]);
+        self.position += read;
+
+        if self.position == self.plaintext.expose_borrowed().len() {
+            self.plaintext.clear_and_set(&[]);
+            self.position = 0;
+        }
+
+        Ok(read)
+    }
+}
+
+impl<R: Read> Drop for SealedReader<R> {
+    fn drop(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
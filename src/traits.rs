@@ -5,3 +5,26 @@ pub trait ToBlake3Hash {
     /// The outcome of hashing `Self` with `blake3::hash`
     fn hash(&self) -> blake3::Hash;
 }
+
+/// Common behaviour of the zeroizing byte containers [crate::ZeroizeArray], [crate::ZeroizeBytesArray]
+/// and [crate::ZeroizeBytes] so that generic code can accept `impl SecretBytes` instead of one container
+pub trait SecretBytes: zeroize::Zeroize {
+    /// Borrow the secret bytes
+    fn expose(&self) -> &[u8];
+
+    /// Create the container from a slice returning an error if the container
+    /// has a fixed length which the slice does not match
+    fn from_slice(value: &[u8]) -> crate::MemSecurityResult<Self>
+    where
+        Self: Sized;
+
+    /// The number of secret bytes
+    fn len(&self) -> usize {
+        self.expose().len()
+    }
+
+    /// Returns `true` if the container holds no bytes
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{MemSecurityErr, MemSecurityResult, ToBlake3Hash};
+use crate::{MemSecurityErr, MemSecurityResult, SecretBytes, ToBlake3Hash};
 use arrayvec::ArrayVec;
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytes::{BufMut, BytesMut};
 use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 #[cfg(feature = "random")]
@@ -47,8 +48,9 @@ impl ZeroizeByte {
 
     /// Clone the array
     #[cfg(feature = "clonable_mem")]
+    #[deprecated(note = "use the `Clone` trait implementation instead")]
     pub fn clone_inner(&self) -> ZeroizeByte {
-        Self(self.0)
+        self.clone()
     }
 
     /// Own this array
@@ -56,6 +58,11 @@ impl ZeroizeByte {
         self
     }
 
+    /// Compare the byte with `other` in constant time, for example against a constant tag or version byte
+    pub fn ct_eq_u8(&self, other: u8) -> bool {
+        self.0.ct_eq(&other).into()
+    }
+
     /// Generate some random byte and initialize an new `ZeroizeByte` in the process.
     #[cfg(feature = "random")]
     pub fn csprng() -> Self {
@@ -67,12 +74,39 @@ impl ZeroizeByte {
 
 impl PartialEq for ZeroizeByte {
     fn eq(&self, other: &Self) -> bool {
-        blake3::hash(&self.0.to_le_bytes()) == blake3::hash(&other.0.to_le_bytes())
+        self.ct_eq(other).into()
+    }
+}
+
+impl ConstantTimeEq for ZeroizeByte {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl From<u8> for ZeroizeByte {
+    fn from(value: u8) -> Self {
+        ZeroizeByte(value)
+    }
+}
+
+/// Copies the byte out, the [ZeroizeByte] is zeroized when it is dropped at the end of the conversion
+#[cfg(feature = "clonable_mem")]
+impl From<ZeroizeByte> for u8 {
+    fn from(value: ZeroizeByte) -> Self {
+        value.0
     }
 }
 
 impl Eq for ZeroizeByte {}
 
+#[cfg(feature = "clonable_mem")]
+impl Clone for ZeroizeByte {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
 impl Zeroize for ZeroizeByte {
     fn zeroize(&mut self) {
         self.0 = 0;
@@ -102,6 +136,16 @@ impl<const N: usize> AsRef<[u8]> for ZeroizeArray<N> {
     }
 }
 
+impl<const N: usize> SecretBytes for ZeroizeArray<N> {
+    fn expose(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn from_slice(value: &[u8]) -> MemSecurityResult<Self> {
+        Self::new_from_slice(value)
+    }
+}
+
 impl<const N: usize> fmt::Debug for ZeroizeArray<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -120,17 +164,132 @@ impl<const N: usize> PartialEq for ZeroizeArray<N> {
 
 impl<const N: usize> Eq for ZeroizeArray<N> {}
 
+impl<const N: usize> ConstantTimeEq for ZeroizeArray<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// Serializes as [REDACTED_PLACEHOLDER] so that deriving `Serialize` on a containing type does not leak the secret.
+/// Use [RevealSerialize] to serialize the actual bytes.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for ZeroizeArray<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED_PLACEHOLDER)
+    }
+}
+
+/// The order is over the blake3 digests of the arrays, not over the secret bytes themselves,
+/// which keeps it consistent with [PartialEq] and means sorting does not leak the ordering of the secrets.
+/// This allows a [ZeroizeArray] to be used as a key in a `BTreeMap` or `BTreeSet`.
+impl<const N: usize> PartialOrd for ZeroizeArray<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for ZeroizeArray<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        blake3::hash(&self.0)
+            .as_bytes()
+            .cmp(blake3::hash(&other.0).as_bytes())
+    }
+}
+
+#[cfg(feature = "clonable_mem")]
+impl<const N: usize> Clone for ZeroizeArray<N> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
 impl<const N: usize> ZeroizeArray<N> {
-    /// Initialize a ZeroizeArray with the value of specified by the array of bytes
+    /// Initialize a ZeroizeArray with the value of specified by the array of bytes.
+    /// A zero-length array is rejected at compile time.
     pub fn new(value: [u8; N]) -> Self {
+        const { assert!(N > 0, "A `ZeroizeArray` cannot be zero-length") };
+
         ZeroizeArray(value)
     }
 
-    /// Initialize a new array which is zeroed bytes of len `N` as specified by the generic `const N: usize`
+    /// Initialize a new array which is zeroed bytes of len `N` as specified by the generic `const N: usize`.
+    /// A zero-length array is rejected at compile time.
     pub fn new_zeroed() -> Self {
+        const { assert!(N > 0, "A `ZeroizeArray` cannot be zero-length") };
+
         ZeroizeArray([0u8; N])
     }
 
+    /// Initialize a new array filled with cryptographically secure random bytes, for example a random key.
+    /// The bytes are generated directly into the internal storage so no copy of them exists outside the array.
+    #[cfg(feature = "random")]
+    pub fn generate() -> Self {
+        use rand_chacha::ChaCha20Rng;
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut outcome = Self::new_zeroed();
+        ChaCha20Rng::from_entropy().fill_bytes(&mut outcome.0);
+        #[cfg(feature = "hwrng_mix")]
+        crate::random::mix_hardware_rng(&mut outcome.0);
+
+        outcome
+    }
+
+    /// Borrow the internal storage mutably so it can be filled in place, for example by decrypting into it
+    #[cfg(feature = "encryption")]
+    pub(crate) fn expose_borrowed_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+
+    /// The length of the array as specified by the generic `const N: usize`
+    pub const fn len() -> usize {
+        N
+    }
+
+    /// Read exactly `N` bytes from `reader` directly into the internal storage of a new array.
+    /// If reading fails the partially read array is zeroized before the error is returned.
+    pub fn from_reader<R: std::io::Read>(reader: &mut R) -> MemSecurityResult<Self> {
+        let mut outcome = Self::new_zeroed();
+
+        if let Err(error) = reader.read_exact(&mut outcome.0) {
+            outcome.wipe();
+
+            return Err(error.into());
+        }
+
+        Ok(outcome)
+    }
+
+    /// Read exactly `N` bytes from `reader`, for example a key sent over a socket, calling `read` until the array
+    /// is full instead of relying on the `read_exact` implementation of the reader. Reads interrupted by a signal
+    /// are retried. If the reader reaches its end early, [MemSecurityErr::Io] with `UnexpectedEof` is returned,
+    /// and on that or any other error the bytes read so far are zeroized before the error is returned.
+    pub fn read_full<R: std::io::Read>(reader: &mut R) -> MemSecurityResult<Self> {
+        use std::io::ErrorKind;
+
+        let mut outcome = Self::new_zeroed();
+        let mut filled = 0usize;
+
+        while filled < N {
+            let error = match reader.read(&mut outcome.0[filled..]) {
+                Ok(0) => MemSecurityErr::Io(ErrorKind::UnexpectedEof),
+                Ok(read) => {
+                    filled += read;
+
+                    continue;
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => error.into(),
+            };
+
+            outcome.wipe();
+
+            return Err(error);
+        }
+
+        Ok(outcome)
+    }
+
     /// File the current array with new values specified by the method parameter `value: [u8; N]`
     pub fn fill_from_array(mut self, value: [u8; N]) -> Self {
         self.0.copy_from_slice(&value);
@@ -163,6 +322,16 @@ impl<const N: usize> ZeroizeArray<N> {
         Ok(outcome)
     }
 
+    /// Move the bytes of `value` into a new array and wipe the `Vec` including its spare capacity.
+    /// Returns [MemSecurityErr::InvalidSliceLength] if the length of `value` is not `N`, the `Vec` is still wiped.
+    pub fn from_vec_zeroizing(mut value: Vec<u8>) -> MemSecurityResult<Self> {
+        let outcome = Self::new_from_slice(&value);
+
+        value.zeroize();
+
+        outcome
+    }
+
     /// Fill the current array with new values specified by the method parameter `value: [u8; N]`
     pub fn fill_from_slice(mut self, value: &[u8]) -> MemSecurityResult<Self> {
         let array: [u8; N] = match value.try_into() {
@@ -208,38 +377,526 @@ impl<const N: usize> ZeroizeArray<N> {
         &self.0
     }
 
+    /// Write the bytes of the array to `writer` directly from the internal storage without making a copy.
+    /// Once written the bytes are outside the zeroization guarantees of this crate, for example when piping a
+    /// decrypted key to a subprocess or a file, so the caller is responsible for wiping them at the destination.
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> MemSecurityResult<()> {
+        writer.write_all(&self.0)?;
+
+        Ok(())
+    }
+
     /// Clone the array
     #[cfg(feature = "clonable_mem")]
+    #[deprecated(note = "use the `Clone` trait implementation instead")]
     pub fn clone_inner(&self) -> ZeroizeArray<N> {
-        Self(self.0)
+        self.clone()
+    }
+
+    /// Own this array
+    pub fn own(self) -> Self {
+        self
+    }
+
+    /// Insert a value at index specified in the array
+    ///
+    /// #### Panics
+    /// Panics if `index` is out of bounds. Use [ZeroizeArray::set_checked] when the index
+    /// comes from parsed or untrusted input.
+    pub fn insert(&mut self, index: usize, value: u8) -> &mut Self {
+        self.0[index] = value;
+
+        self
+    }
+
+    /// Wipe the array without consuming it so that it can be refilled and reused.
+    /// The bytes are overwritten using the volatile writes of the `zeroize` crate which the
+    /// optimizer cannot elide, then the array is verified to be all zeroes.
+    pub fn wipe(&mut self) -> &mut Self {
+        self.0.zeroize();
+
+        assert_eq!(self.0, [0u8; N]); //Must panic if memory cannot be zeroized
+
+        self
+    }
+
+    /// Get the byte at the index specified returning `None` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.0.get(index).copied()
+    }
+
+    /// Set the byte at the index specified returning an error instead of panicking if the index is out of bounds
+    pub fn set_checked(&mut self, index: usize, value: u8) -> MemSecurityResult<&mut Self> {
+        match self.0.get_mut(index) {
+            Some(byte) => *byte = value,
+            None => return Err(MemSecurityErr::IndexOutOfBounds { index, len: N }),
+        }
+
+        Ok(self)
+    }
+
+    /// Split the array into two zeroizing arrays holding the first `L` bytes and the remaining `R` bytes,
+    /// for example splitting an [crate::Ed25519KeyPair] into its secret and public halves.
+    /// `L + R` must be equal to `N` which is checked at compile time. This array is zeroized after the split.
+    pub fn split_at<const L: usize, const R: usize>(self) -> (ZeroizeArray<L>, ZeroizeArray<R>) {
+        const { assert!(L + R == N, "The lengths of the halves must add up to `N`") };
+
+        let mut left = ZeroizeArray::<L>::new_zeroed();
+        let mut right = ZeroizeArray::<R>::new_zeroed();
+
+        left.0.copy_from_slice(&self.0[..L]);
+        right.0.copy_from_slice(&self.0[L..]);
+
+        (left, right)
+    }
+
+    /// Concatenate this array with `other` into a zeroizing array of length `OUT`, for example building an
+    /// [crate::Ed25519KeyPair] from its secret and public halves. `N + R` must be equal to `OUT` which is checked
+    /// at compile time. Both input arrays are zeroized after the concatenation.
+    pub fn concat<const R: usize, const OUT: usize>(
+        self,
+        other: ZeroizeArray<R>,
+    ) -> ZeroizeArray<OUT> {
+        const {
+            assert!(
+                N + R == OUT,
+                "The lengths of the arrays must add up to `OUT`"
+            )
+        };
+
+        let mut outcome = ZeroizeArray::<OUT>::new_zeroed();
+
+        outcome.0[..N].copy_from_slice(&self.0);
+        outcome.0[N..].copy_from_slice(&other.0);
+
+        outcome
+    }
+
+    /// XOR `mask` into this array in place, for example masking a key with a random mask from
+    /// [crate::CsprngArray] to split it into two shares. This runs in constant time and does not allocate.
+    pub fn xor_with(&mut self, mask: &ZeroizeArray<N>) -> &mut Self {
+        self.0
+            .iter_mut()
+            .zip(mask.0.iter())
+            .for_each(|(byte, mask_byte)| *byte ^= mask_byte);
+
+        self
+    }
+
+    /// Apply `f` to every byte producing a new zeroizing array, for example applying a byte-wise S-box.
+    /// The bytes are written straight into the new array so no intermediate non-zeroizing buffer is created.
+    pub fn map(&self, f: impl Fn(u8) -> u8) -> ZeroizeArray<N> {
+        let mut outcome = ZeroizeArray::<N>::new_zeroed();
+
+        outcome
+            .0
+            .iter_mut()
+            .zip(self.0.iter())
+            .for_each(|(mapped, byte)| *mapped = f(*byte));
+
+        outcome
+    }
+
+    /// Fold every byte of the array into an accumulator starting from `init`, for example to compute a checksum
+    /// or parity for an integrity check without copying the secret bytes out of the array.
+    pub fn fold_bytes<B>(&self, init: B, f: impl Fn(B, u8) -> B) -> B {
+        self.0
+            .iter()
+            .fold(init, |accumulator, byte| f(accumulator, *byte))
+    }
+
+    /// Apply `f` to every byte of the array in place
+    pub fn map_in_place(&mut self, f: impl Fn(u8) -> u8) -> &mut Self {
+        self.0.iter_mut().for_each(|byte| *byte = f(*byte));
+
+        self
+    }
+
+    /// Rotate the bytes of the array in place so that the byte at index `mid % N` becomes the first byte.
+    /// The work done depends only on `mid` and `N`, not on the secret bytes.
+    pub fn rotate_left(&mut self, mid: usize) -> &mut Self {
+        self.0.rotate_left(mid % N);
+
+        self
+    }
+
+    /// Rotate the bytes of the array in place so that the last `k % N` bytes move to the front.
+    /// The work done depends only on `k` and `N`, not on the secret bytes.
+    pub fn rotate_right(&mut self, k: usize) -> &mut Self {
+        self.0.rotate_right(k % N);
+
+        self
+    }
+
+    /// Treat the array as a big-endian unsigned integer and add one to it in place, wrapping to zero on overflow.
+    /// This makes the array usable as a zeroizing counter, for example for counter based nonces.
+    /// The carry is propagated through every byte without branching so this runs in constant time.
+    pub fn increment_be(&mut self) -> &mut Self {
+        let mut carry = 1u16;
+
+        self.0.iter_mut().rev().for_each(|byte| {
+            let sum = *byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        });
+
+        self
+    }
+
+    /// Check whether `needle` is in `haystack`, for example an allowlist of tokens, without leaking through timing
+    /// which entry matched or whether any entry matched early. Every entry is compared in constant time and
+    /// the whole slice is always scanned.
+    pub fn constant_time_contains(
+        haystack: &[ZeroizeArray<N>],
+        needle: &ZeroizeArray<N>,
+    ) -> Choice {
+        haystack
+            .iter()
+            .fold(Choice::from(0), |found, entry| found | entry.ct_eq(needle))
     }
 
-    /// Own this array
-    pub fn own(self) -> Self {
-        self
+    /// Reverse the order of the bytes of the array in place, for example to import a key from a system using
+    /// the opposite byte order. The bytes are swapped within the array so no copy of them is made.
+    pub fn reverse(&mut self) -> &mut Self {
+        self.0.reverse();
+
+        self
+    }
+
+    /// Treat the array as a big-endian counter, for example a nonce, and add `n` to it in place with the carry
+    /// propagated across every byte. Every byte is visited and the carry is added arithmetically, so the time taken
+    /// does not depend on the value of the counter. Returns [MemSecurityErr::CounterOverflow] and leaves the array
+    /// unchanged if the sum does not fit in `N` bytes.
+    pub fn add_scalar(&mut self, n: u64) -> MemSecurityResult<&mut Self> {
+        let addend = n.to_le_bytes();
+
+        let mut sum = self.0;
+        let mut carry = 0u16;
+
+        sum.iter_mut().rev().enumerate().for_each(|(index, byte)| {
+            let total =
+                u16::from(*byte) + u16::from(addend.get(index).copied().unwrap_or(0)) + carry;
+
+            *byte = total as u8;
+            carry = total >> 8;
+        });
+
+        // Bytes of `n` beyond the width of the array cannot be added without wrapping
+        let unused = addend
+            .iter()
+            .skip(N)
+            .fold(0u8, |accumulator, byte| accumulator | byte);
+
+        if carry != 0 || unused != 0 {
+            sum.zeroize();
+
+            return Err(MemSecurityErr::CounterOverflow);
+        }
+
+        self.0.copy_from_slice(&sum);
+        sum.zeroize();
+
+        Ok(self)
+    }
+
+    /// Treat the array as an integer in the native byte order of the target and convert it to big-endian in place.
+    /// This reverses the bytes on little-endian targets and does nothing on big-endian targets.
+    pub fn to_be(&mut self) -> &mut Self {
+        if cfg!(target_endian = "little") {
+            self.reverse();
+        }
+
+        self
+    }
+
+    /// Treat the array as an integer in the native byte order of the target and convert it to little-endian in place.
+    /// This reverses the bytes on big-endian targets and does nothing on little-endian targets.
+    pub fn to_le(&mut self) -> &mut Self {
+        if cfg!(target_endian = "big") {
+            self.reverse();
+        }
+
+        self
+    }
+
+    /// XOR `a` and `b` into a new zeroizing array leaving both inputs untouched
+    pub fn xor_into(a: &ZeroizeArray<N>, b: &ZeroizeArray<N>) -> ZeroizeArray<N> {
+        let mut outcome = ZeroizeArray::<N>::new_zeroed();
+
+        outcome
+            .0
+            .iter_mut()
+            .zip(a.0.iter().zip(b.0.iter()))
+            .for_each(|(byte, (a_byte, b_byte))| *byte = a_byte ^ b_byte);
+
+        outcome
+    }
+}
+
+/// XOR the arrays byte by byte in constant time. The result is written into the storage of the left operand
+/// and the right operand is zeroized when it is dropped at the end of the operation.
+impl<const N: usize> core::ops::BitXor for ZeroizeArray<N> {
+    type Output = ZeroizeArray<N>;
+
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self.0
+            .iter_mut()
+            .zip(rhs.0.iter())
+            .for_each(|(byte, rhs_byte)| *byte ^= rhs_byte);
+
+        self
+    }
+}
+
+/// AND the arrays byte by byte in constant time. The result is written into the storage of the left operand
+/// and the right operand is zeroized when it is dropped at the end of the operation.
+impl<const N: usize> core::ops::BitAnd for ZeroizeArray<N> {
+    type Output = ZeroizeArray<N>;
+
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self.0
+            .iter_mut()
+            .zip(rhs.0.iter())
+            .for_each(|(byte, rhs_byte)| *byte &= rhs_byte);
+
+        self
+    }
+}
+
+/// OR the arrays byte by byte in constant time. The result is written into the storage of the left operand
+/// and the right operand is zeroized when it is dropped at the end of the operation.
+impl<const N: usize> core::ops::BitOr for ZeroizeArray<N> {
+    type Output = ZeroizeArray<N>;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self.0
+            .iter_mut()
+            .zip(rhs.0.iter())
+            .for_each(|(byte, rhs_byte)| *byte |= rhs_byte);
+
+        self
+    }
+}
+
+impl<const N: usize> Zeroize for ZeroizeArray<N> {
+    fn zeroize(&mut self) {
+        self.wipe();
+    }
+}
+
+impl<const N: usize> Drop for ZeroizeArray<N> {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for ZeroizeArray<N> {}
+
+/// The alignment in bytes of a [PageAlignedZeroizeArray] which is the common 4KiB memory page size
+pub const PAGE_ALIGNMENT: usize = 4096;
+
+/// This is a array whose size is specified as a const generic `N` that starts on a memory page boundary
+/// and is padded to a whole number of pages. `mlock` locks whole pages so when `N` is less than or equal to
+/// [PAGE_ALIGNMENT] the array occupies exactly one page and locking it pins only the secret and no adjacent data.
+/// Use [PageAlignedZeroizeArray::new_boxed] or [PageAlignedZeroizeArray::new_zeroed] to allocate it on the heap
+/// inside a [PageAlignedZeroizeBox] which also tracks whether the pages are locked.
+/// #### Structure
+/// ```rust
+/// #[repr(C, align(4096))]
+/// pub struct PageAlignedZeroizeArray<const N: usize>([u8; N]);
+/// ```
+#[repr(C, align(4096))]
+pub struct PageAlignedZeroizeArray<const N: usize>([u8; N]);
+
+impl<const N: usize> PageAlignedZeroizeArray<N> {
+    /// Allocate a new page aligned array of zeroed bytes of len `N` directly on the heap
+    /// so the array is never built on the stack first
+    #[allow(unsafe_code)]
+    pub fn new_zeroed() -> PageAlignedZeroizeBox<N> {
+        let layout = std::alloc::Layout::new::<Self>();
+
+        let array = if layout.size() == 0 {
+            Box::new(PageAlignedZeroizeArray([0u8; N]))
+        } else {
+            // SAFETY: the layout has a non-zero size and the all zero bit pattern is a valid `[u8; N]`.
+            // The pointer is allocated by the global allocator with the layout of `Self` as `Box` requires.
+            unsafe {
+                let ptr = std::alloc::alloc_zeroed(layout);
+                if ptr.is_null() {
+                    std::alloc::handle_alloc_error(layout);
+                }
+
+                Box::from_raw(ptr.cast::<Self>())
+            }
+        };
+
+        PageAlignedZeroizeBox {
+            array,
+            locked: false,
+        }
+    }
+
+    /// Allocate a new page aligned array on the heap and copy the bytes of `value` into it.
+    /// The caller's `value` is zeroed after it has been copied.
+    pub fn new_boxed(mut value: [u8; N]) -> PageAlignedZeroizeBox<N> {
+        let mut outcome = Self::new_zeroed();
+        outcome.array.0.copy_from_slice(&value);
+        value.zeroize();
+
+        outcome
+    }
+
+    /// Fill the current array with new values specified by the method parameter `value: &[u8]`
+    pub fn fill_from_slice_borrowed(&mut self, value: &[u8]) -> MemSecurityResult<&mut Self> {
+        if value.len() != N {
+            return Err(MemSecurityErr::InvalidSliceLength {
+                expected: N,
+                found: value.len(),
+            });
+        }
+
+        self.0.copy_from_slice(value);
+
+        Ok(self)
+    }
+
+    /// Expose the internal as an borrowed array
+    pub fn expose_borrowed(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// The number of bytes occupied by this array including the padding up to the page boundary
+    pub const fn page_span() -> usize {
+        core::mem::size_of::<Self>()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for PageAlignedZeroizeArray<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.expose_borrowed()
+    }
+}
+
+impl<const N: usize> fmt::Debug for PageAlignedZeroizeArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PageAlignedZeroizeArray<const N: usize>({:?})",
+            &blake3::hash(&self.0)
+        )
+    }
+}
+
+impl<const N: usize> PartialEq for PageAlignedZeroizeArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        blake3::hash(&self.0) == blake3::hash(&other.0)
+    }
+}
+
+impl<const N: usize> Eq for PageAlignedZeroizeArray<N> {}
+
+impl<const N: usize> Zeroize for PageAlignedZeroizeArray<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> Drop for PageAlignedZeroizeArray<N> {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for PageAlignedZeroizeArray<N> {}
+
+/// Owns a heap allocated [PageAlignedZeroizeArray] and whether its pages are locked. The flag is kept
+/// outside the page aligned allocation so the array alone fills its pages.
+/// #### Structure
+/// ```rust
+/// use memsecurity::PageAlignedZeroizeArray;
+///
+/// pub struct PageAlignedZeroizeBox<const N: usize> {
+///     array: Box<PageAlignedZeroizeArray<N>>,
+///     locked: bool,
+/// }
+/// ```
+pub struct PageAlignedZeroizeBox<const N: usize> {
+    array: Box<PageAlignedZeroizeArray<N>>,
+    locked: bool,
+}
+
+impl<const N: usize> PageAlignedZeroizeBox<N> {
+    /// Returns `true` if the pages holding this array are currently locked
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// `mlock` the pages holding this array, returning `true` if the operating system locked them
+    #[cfg(feature = "encryption")]
+    #[allow(unsafe_code)]
+    pub fn mlock(&mut self) -> bool {
+        let ptr = (&mut *self.array as *mut PageAlignedZeroizeArray<N>).cast::<u8>();
+        self.locked = unsafe { memsec::mlock(ptr, PageAlignedZeroizeArray::<N>::page_span()) };
+
+        self.locked
+    }
+
+    /// `munlock` the pages holding this array. The contents of the array are zeroed in the process.
+    #[cfg(feature = "encryption")]
+    #[allow(unsafe_code)]
+    pub fn munlock(&mut self) -> bool {
+        let ptr = (&mut *self.array as *mut PageAlignedZeroizeArray<N>).cast::<u8>();
+        let outcome = unsafe { memsec::munlock(ptr, PageAlignedZeroizeArray::<N>::page_span()) };
+        self.locked = false;
+
+        outcome
+    }
+}
+
+impl<const N: usize> core::ops::Deref for PageAlignedZeroizeBox<N> {
+    type Target = PageAlignedZeroizeArray<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.array
+    }
+}
+
+impl<const N: usize> core::ops::DerefMut for PageAlignedZeroizeBox<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.array
     }
+}
 
-    /// Insert a value at index specified in the array
-    pub fn insert(&mut self, index: usize, value: u8) -> &mut Self {
-        self.0[index] = value;
+impl<const N: usize> AsRef<[u8]> for PageAlignedZeroizeBox<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.array.expose_borrowed()
+    }
+}
 
-        self
+impl<const N: usize> fmt::Debug for PageAlignedZeroizeBox<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.array, f)
     }
 }
 
-impl<const N: usize> Zeroize for ZeroizeArray<N> {
+impl<const N: usize> Zeroize for PageAlignedZeroizeBox<N> {
     fn zeroize(&mut self) {
-        self.0[..].copy_from_slice(&[0u8; N]);
+        self.array.zeroize();
     }
 }
 
-impl<const N: usize> Drop for ZeroizeArray<N> {
+impl<const N: usize> Drop for PageAlignedZeroizeBox<N> {
     fn drop(&mut self) {
+        #[cfg(feature = "encryption")]
+        if self.locked {
+            self.munlock();
+        }
+
         self.zeroize()
     }
 }
 
-impl<const N: usize> ZeroizeOnDrop for ZeroizeArray<N> {}
+impl<const N: usize> ZeroizeOnDrop for PageAlignedZeroizeBox<N> {}
 
 /// This is an array of variable length bytes that can be zeroed out on drop.
 /// #### Structure
@@ -253,11 +910,19 @@ impl<const N: usize> ZeroizeOnDrop for ZeroizeArray<N> {}
 pub struct ZeroizeBytesArray<const N: usize>(BytesMut);
 
 impl<const N: usize> ZeroizeBytesArray<N> {
-    /// Initialize the array with an initial length of `N`
+    /// Initialize an empty array with a capacity of `N` bytes.
+    /// The length of the array is zero so it cannot be indexed until bytes are added,
+    /// use [ZeroizeBytesArray::new_zeroed] to get `N` usable bytes immediately.
     pub fn new() -> Self {
         ZeroizeBytesArray(BytesMut::with_capacity(N))
     }
 
+    /// Initialize the array with a length of `N` bytes which are all zeroes.
+    /// Unlike [ZeroizeBytesArray::new] every byte of the array can be indexed immediately.
+    pub fn new_zeroed() -> Self {
+        ZeroizeBytesArray(BytesMut::zeroed(N))
+    }
+
     /// Initialize the array and set the internal value of the array to the value specified by method argument
     pub fn new_with_data(value: [u8; N]) -> Self {
         let mut value_bytes = BytesMut::with_capacity(N);
@@ -313,10 +978,40 @@ impl<const N: usize> ZeroizeBytesArray<N> {
         &self.0
     }
 
+    /// Wipe every byte of the allocation and borrow the now empty internal value so it can be refilled in place
+    #[cfg(feature = "encryption")]
+    pub(crate) fn wiped_bytes_mut(&mut self) -> &mut BytesMut {
+        wipe_bytes_mut(&mut self.0);
+
+        &mut self.0
+    }
+
+    /// Split bytes laid out as `ciphertext || tag` into the ciphertext and the trailing [crate::ASCON128_TAG_LEN] byte tag, for example to store the tag
+    /// separately from the ciphertext. Returns [MemSecurityErr::CiphertextTooShort] if the bytes are shorter than a tag.
+    #[cfg(feature = "encryption")]
+    pub fn split_tag(&self) -> MemSecurityResult<(&[u8], &[u8])> {
+        let len = self.0.len();
+
+        if len < crate::ASCON128_TAG_LEN {
+            return Err(MemSecurityErr::CiphertextTooShort {
+                min: crate::ASCON128_TAG_LEN,
+                found: len,
+            });
+        }
+
+        Ok(self.0.split_at(len - crate::ASCON128_TAG_LEN))
+    }
+
+    /// Move the bytes of this array into a [ZeroizeBytes] without copying them, consuming this array
+    pub fn into_zeroize_bytes(mut self) -> ZeroizeBytes {
+        ZeroizeBytes(core::mem::take(&mut self.0), None)
+    }
+
     /// Clone the array
     #[cfg(feature = "clonable_mem")]
+    #[deprecated(note = "use the `Clone` trait implementation instead")]
     pub fn clone_inner(&self) -> ZeroizeBytesArray<N> {
-        Self(self.0.clone())
+        self.clone()
     }
 }
 
@@ -326,6 +1021,26 @@ impl<const N: usize> AsRef<[u8]> for ZeroizeBytesArray<N> {
     }
 }
 
+impl<const N: usize> SecretBytes for ZeroizeBytesArray<N> {
+    fn expose(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn from_slice(value: &[u8]) -> MemSecurityResult<Self> {
+        if value.len() != N {
+            return Err(MemSecurityErr::InvalidSliceLength {
+                expected: N,
+                found: value.len(),
+            });
+        }
+
+        let mut outcome = Self::new();
+        outcome.0.put(value);
+
+        Ok(outcome)
+    }
+}
+
 impl<const N: usize> PartialEq for ZeroizeBytesArray<N> {
     fn eq(&self, other: &Self) -> bool {
         blake3::hash(&self.0) == blake3::hash(&other.0)
@@ -334,6 +1049,13 @@ impl<const N: usize> PartialEq for ZeroizeBytesArray<N> {
 
 impl<const N: usize> Eq for ZeroizeBytesArray<N> {}
 
+#[cfg(feature = "clonable_mem")]
+impl<const N: usize> Clone for ZeroizeBytesArray<N> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<const N: usize> Default for ZeroizeBytesArray<N> {
     fn default() -> Self {
         Self::new()
@@ -350,9 +1072,10 @@ impl<const N: usize> fmt::Debug for ZeroizeBytesArray<N> {
     }
 }
 
+/// Overwrites the whole allocation, including the spare capacity, before the length is reset
 impl<const N: usize> Zeroize for ZeroizeBytesArray<N> {
     fn zeroize(&mut self) {
-        self.0.clear()
+        wipe_bytes_mut(&mut self.0)
     }
 }
 
@@ -370,15 +1093,15 @@ impl<const N: usize> ZeroizeOnDrop for ZeroizeBytesArray<N> {}
 /// ```rust
 /// use bytes::BytesMut;
 ///
-/// pub struct ZeroizeBytes(BytesMut);
+/// pub struct ZeroizeBytes(BytesMut, Option<(usize, usize)>);
 /// ```
 #[derive(BorshSerialize, BorshDeserialize)]
-pub struct ZeroizeBytes(BytesMut);
+pub struct ZeroizeBytes(BytesMut, #[borsh(skip)] Option<(usize, usize)>);
 
 impl ZeroizeBytes {
     /// Create a new array with no allocation and no specified capacity
     pub fn new() -> Self {
-        ZeroizeBytes(BytesMut::new())
+        ZeroizeBytes(BytesMut::new(), None)
     }
 
     /// Initialize the array and set the internal value of the array to the value specified by method argument
@@ -386,35 +1109,217 @@ impl ZeroizeBytes {
         let mut value_bytes = BytesMut::new();
         value_bytes.put(value);
 
-        ZeroizeBytes(value_bytes)
+        ZeroizeBytes(value_bytes, None)
     }
 
-    /// Set the internal value of the array to the value specified by method argument value which is a `BytesMut`
-    pub fn set_bytes_mut(&mut self, value: BytesMut) -> &mut Self {
-        self.0.put(&value[..]);
+    /// Concatenate `parts`, such as [ZeroizeArray]s of different lengths, into a new [ZeroizeBytes]
+    /// for example to assemble a protocol message from secret fields. The total length is reserved up front
+    /// so the bytes are copied once into a single allocation with no intermediate buffers.
+    pub fn concat_arrays(parts: &[&dyn AsRef<[u8]>]) -> Self {
+        let len = parts.iter().map(|part| part.as_ref().len()).sum();
+
+        let mut outcome = ZeroizeBytes::new_with_capacity(len);
+        parts.iter().for_each(|part| {
+            outcome.extend_from_slice(part.as_ref());
+        });
+
+        outcome
+    }
+
+    /// Append the bytes of the `BytesMut` specified by method argument value to the internal value.
+    /// The `value` is wiped after it has been copied.
+    #[deprecated(note = "use `extend_from_slice` to append or `clear_and_set` to replace")]
+    pub fn set_bytes_mut(&mut self, mut value: BytesMut) -> &mut Self {
+        self.extend_from_slice(&value[..]);
+        wipe_bytes_mut(&mut value);
 
         self
     }
 
-    /// Sets the internal value to the new value
+    /// Replace the internal value with the new value
+    #[deprecated(note = "use `clear_and_set` to replace or `extend_from_slice` to append")]
     pub fn set(&mut self, value: &[u8]) -> &mut Self {
-        let mut container = BytesMut::new();
-        container.put(value);
-        self.0 = container;
+        self.clear_and_set(value)
+    }
+
+    /// Append the bytes of `value` after the existing bytes. If the capacity is exceeded
+    /// the bytes are moved to a larger allocation and the old backing buffer is wiped.
+    pub fn extend_from_slice(&mut self, value: &[u8]) -> &mut Self {
+        self.reserve_wiped(value.len());
+        self.0.put(value);
+
+        self
+    }
+
+    /// Wipe the existing bytes and replace them with the bytes of `value`
+    pub fn clear_and_set(&mut self, value: &[u8]) -> &mut Self {
+        self.0.zeroize();
+        self.0.clear();
+
+        self.extend_from_slice(value)
+    }
+
+    /// Resize the bytes to `new_len` so the buffer can be reused for values of varying size.
+    /// When shrinking, the removed tail is wiped before it is truncated. When growing, the new bytes are zeroes
+    /// and if the capacity is exceeded the old backing buffer is wiped.
+    pub fn resize_zeroed(&mut self, new_len: usize) -> &mut Self {
+        let len = self.0.len();
+
+        if new_len < len {
+            self.0[new_len..].zeroize();
+            self.0.truncate(new_len);
+        } else {
+            self.reserve_wiped(new_len - len);
+            self.0.resize(new_len, 0);
+        }
+
+        self
+    }
+
+    /// Truncate the bytes to `new_len` and release the unused capacity, for example after extracting what is
+    /// needed from a large decrypted payload. The bytes are copied into an allocation that fits them exactly and
+    /// the whole old allocation, including the truncated tail and spare capacity, is wiped before it is released.
+    /// If `new_len` is greater than the current length only the spare capacity is released.
+    pub fn wipe_and_shrink_to_fit(&mut self, new_len: usize) -> &mut Self {
+        let new_len = new_len.min(self.0.len());
+
+        let mut shrunk = BytesMut::with_capacity(new_len);
+        let locked = self.lock_like_self(&mut shrunk);
+        shrunk.put(&self.0[..new_len]);
+
+        self.release_locked_region();
+        let mut old = core::mem::replace(&mut self.0, shrunk);
+        wipe_bytes_mut(&mut old);
+        self.1 = locked;
 
         self
     }
 
+    /// Run `f` with mutable access to the bytes, for example to unmask decrypted bytes in place without copying
+    /// them out. The mutable borrow cannot outlive `f` so the bytes stay inside this zeroizing container.
+    pub fn with_bytes_mut<R>(&mut self, f: impl FnOnce(&mut [u8]) -> R) -> R {
+        f(&mut self.0[..])
+    }
+
+    /// Split the bytes at `at`, keeping `[0, at)` and returning `[at, len)` in a new [ZeroizeBytes],
+    /// for example to separate a header from a body. The tail is copied into its own allocation and wiped
+    /// from this buffer so both halves are zeroed when dropped.
+    /// Returns [MemSecurityErr::IndexOutOfBounds] if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> MemSecurityResult<ZeroizeBytes> {
+        if at > self.0.len() {
+            return Err(MemSecurityErr::IndexOutOfBounds {
+                index: at,
+                len: self.0.len(),
+            });
+        }
+
+        let tail = ZeroizeBytes::new_with_data(&self.0[at..]);
+        self.resize_zeroed(at);
+
+        Ok(tail)
+    }
+
+    /// Compute a Blake3 MAC of the bytes keyed with a caller supplied `key`, without using the sealing key.
+    /// The key stays in its zeroizing storage and the keyed hasher is wiped once the MAC is computed.
+    pub fn keyed_hash(&self, key: &ZeroizeArray<32>) -> blake3::Hash {
+        let mut hasher = blake3::Hasher::new_keyed(key.expose_borrowed());
+        hasher.update(&self.0);
+
+        let outcome = hasher.finalize();
+        hasher.zeroize();
+
+        outcome
+    }
+
+    /// Overwrite the first `len` bytes with zeros in place without changing the length of the buffer,
+    /// for example to wipe a header as soon as it has been parsed while the rest of the buffer is still in use.
+    /// Returns [MemSecurityErr::IndexOutOfBounds] if `len` is greater than the length.
+    pub fn zeroize_prefix(&mut self, len: usize) -> MemSecurityResult<&mut Self> {
+        self.zeroize_range(0, len)
+    }
+
+    /// Overwrite the bytes in `[start, end)` with zeros in place without changing the length of the buffer.
+    /// Returns [MemSecurityErr::IndexOutOfBounds] if `end` is greater than the length or `start` is greater than `end`.
+    pub fn zeroize_range(&mut self, start: usize, end: usize) -> MemSecurityResult<&mut Self> {
+        if end > self.0.len() {
+            return Err(MemSecurityErr::IndexOutOfBounds {
+                index: end,
+                len: self.0.len(),
+            });
+        }
+
+        if start > end {
+            return Err(MemSecurityErr::IndexOutOfBounds {
+                index: start,
+                len: end,
+            });
+        }
+
+        self.0[start..end].zeroize();
+
+        Ok(self)
+    }
+
     /// Add the byte the internal value
     pub fn set_byte(&mut self, value: u8) -> &mut Self {
+        self.reserve_wiped(1);
         self.0.put_u8(value);
 
         self
     }
 
+    /// Ensure there is room for `additional` bytes. If the capacity is too small the bytes are copied
+    /// into a larger allocation and the old backing buffer is wiped before it is released.
+    fn reserve_wiped(&mut self, additional: usize) {
+        if self.0.capacity() - self.0.len() >= additional {
+            return;
+        }
+
+        let mut grown =
+            BytesMut::with_capacity((self.0.len() + additional).max(self.0.capacity() * 2));
+        let locked = self.lock_like_self(&mut grown);
+        grown.put(&self.0[..]);
+
+        self.release_locked_region();
+        let mut old = core::mem::replace(&mut self.0, grown);
+        wipe_bytes_mut(&mut old);
+        self.1 = locked;
+    }
+
+    /// `mlock` the allocation of `bytes` before the bytes of a locked array are moved into it, returning
+    /// the locked region or `None` if this array is not locked or the operating system refused to lock it
+    #[allow(unsafe_code)]
+    fn lock_like_self(&self, bytes: &mut BytesMut) -> Option<(usize, usize)> {
+        #[cfg(feature = "encryption")]
+        if self.1.is_some() && bytes.capacity() > 0 {
+            let (locked_ptr, locked_len) = (bytes.as_mut_ptr(), bytes.capacity());
+
+            if unsafe { memsec::mlock(locked_ptr, locked_len) } {
+                return Some((locked_ptr as usize, locked_len));
+            }
+        }
+
+        #[cfg(not(feature = "encryption"))]
+        let _ = bytes;
+
+        None
+    }
+
+    /// `munlock` the region locked by [ZeroizeBytes::new_locked_capacity] if the bytes still live in it.
+    /// The region is zeroed in the process.
+    #[allow(unsafe_code)]
+    fn release_locked_region(&mut self) {
+        #[cfg(feature = "encryption")]
+        if let Some((locked_ptr, locked_len)) = self.1.take() {
+            if self.0.as_mut_ptr() as usize == locked_ptr && self.0.capacity() == locked_len {
+                unsafe { memsec::munlock(locked_ptr as *mut u8, locked_len) };
+            }
+        }
+    }
+
     /// Initializes the array with a specified capacity
     pub fn new_with_capacity(capacity: usize) -> Self {
-        ZeroizeBytes(BytesMut::with_capacity(capacity))
+        ZeroizeBytes(BytesMut::with_capacity(capacity), None)
     }
 
     /// Expose the internal value
@@ -424,8 +1329,39 @@ impl ZeroizeBytes {
 
     /// Clone the array
     #[cfg(feature = "clonable_mem")]
+    #[deprecated(note = "use the `Clone` trait implementation instead")]
     pub fn clone_inner(&self) -> ZeroizeBytes {
-        Self(self.0.clone())
+        self.clone()
+    }
+
+    /// Initializes the array with a specified capacity whose backing memory is zeroed and `mlock`ed
+    /// before use so that it can be trusted as a scratch buffer for decrypted material.
+    /// Growing or shrinking the allocation locks the new allocation before the bytes are moved into it and
+    /// unlocks and wipes the old one. If the operating system refuses to lock the new allocation the array
+    /// continues unlocked, which [ZeroizeBytes::is_locked] reports.
+    #[cfg(feature = "encryption")]
+    #[allow(unsafe_code)]
+    pub fn new_locked_capacity(capacity: usize) -> MemSecurityResult<Self> {
+        let mut value_bytes = BytesMut::with_capacity(capacity);
+        value_bytes.resize(value_bytes.capacity(), 0);
+        value_bytes.clear();
+
+        let locked_len = value_bytes.capacity();
+        let locked_ptr = value_bytes.as_mut_ptr();
+
+        if !unsafe { memsec::mlock(locked_ptr, locked_len) } {
+            return Err(MemSecurityErr::MlockError);
+        }
+
+        Ok(ZeroizeBytes(
+            value_bytes,
+            Some((locked_ptr as usize, locked_len)),
+        ))
+    }
+
+    /// Returns `true` if the current backing memory of this array is `mlock`ed
+    pub fn is_locked(&self) -> bool {
+        self.1.is_some()
     }
 }
 
@@ -435,12 +1371,44 @@ impl Default for ZeroizeBytes {
     }
 }
 
+/// Growing past the capacity moves the bytes to a larger allocation and wipes the old one,
+/// the lower bound of the size hint of the iterator is reserved up front to avoid most reallocations.
+impl Extend<u8> for ZeroizeBytes {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve_wiped(iter.size_hint().0);
+
+        iter.for_each(|byte| {
+            self.set_byte(byte);
+        });
+    }
+}
+
+impl FromIterator<u8> for ZeroizeBytes {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut outcome = ZeroizeBytes::new();
+        outcome.extend(iter);
+
+        outcome
+    }
+}
+
 impl AsRef<[u8]> for ZeroizeBytes {
     fn as_ref(&self) -> &[u8] {
         self.expose_borrowed()
     }
 }
 
+impl SecretBytes for ZeroizeBytes {
+    fn expose(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn from_slice(value: &[u8]) -> MemSecurityResult<Self> {
+        Ok(Self::new_with_data(value))
+    }
+}
+
 impl PartialEq for ZeroizeBytes {
     fn eq(&self, other: &Self) -> bool {
         blake3::hash(&self.0) == blake3::hash(&other.0)
@@ -449,24 +1417,64 @@ impl PartialEq for ZeroizeBytes {
 
 impl Eq for ZeroizeBytes {}
 
+/// Serializes as [REDACTED_PLACEHOLDER] so that deriving `Serialize` on a containing type does not leak the secret.
+/// Use [RevealSerialize] to serialize the actual bytes.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ZeroizeBytes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED_PLACEHOLDER)
+    }
+}
+
+#[cfg(feature = "clonable_mem")]
+impl Clone for ZeroizeBytes {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), None)
+    }
+}
+
 impl fmt::Debug for ZeroizeBytes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "ZeroizeBytes({:?})", &blake3::hash(&self.0))
     }
 }
 
+/// Overwrites the whole allocation, including the spare capacity, before the length is reset
 impl Zeroize for ZeroizeBytes {
     fn zeroize(&mut self) {
-        self.0.clear()
+        wipe_bytes_mut(&mut self.0)
     }
 }
 
 impl Drop for ZeroizeBytes {
     fn drop(&mut self) {
+        self.release_locked_region();
+
         self.zeroize()
     }
 }
 
+/// Clear `vec` and overwrite all `N` slots of its backing array, including slots left behind by earlier removals,
+/// since `ArrayVec::clear` only resets the length and leaves the bytes of the elements in place
+#[allow(unsafe_code)]
+fn clear_and_wipe_array_vec<T, const N: usize>(vec: &mut ArrayVec<T, N>) {
+    vec.clear();
+
+    // SAFETY: the pointer covers exactly the `N` slots of the backing array and after `clear` every slot is
+    // logically uninitialized, so overwriting it through `MaybeUninit` cannot invalidate a live `T`
+    let storage = unsafe {
+        core::slice::from_raw_parts_mut(vec.as_mut_ptr().cast::<core::mem::MaybeUninit<T>>(), N)
+    };
+    storage.zeroize();
+}
+
+/// Wipe every byte of the allocation backing `bytes` including the spare capacity
+pub(crate) fn wipe_bytes_mut(bytes: &mut BytesMut) {
+    bytes.resize(bytes.capacity(), 0);
+    bytes.zeroize();
+    bytes.clear();
+}
+
 impl ZeroizeOnDrop for ZeroizeBytes {}
 
 /// This is an ArrayVec whose size is specified as a const generic `N` and can be zeroed out when dropped from memory.
@@ -490,17 +1498,17 @@ where
 
     /// Initialize a ZeroizeArray with the value of specified by the array of bytes
     pub fn new_with(value: [T; N]) -> Self {
-        let mut outcome = ArrayVec::<T, N>::new();
-        outcome.try_extend_from_slice(&value).unwrap(); // Should never fail due to const size constraints
-
-        ZeroizeArrayVec(outcome)
+        ZeroizeArrayVec(ArrayVec::from(value))
     }
 
-    /// File the current array with new values specified by the method parameter `value: [u8; N]`
-    pub fn fill_from_slice(&mut self, value: [T; N]) -> &mut Self {
-        self.0.try_extend_from_slice(&value).unwrap(); // Should never fail due to const size constraints
+    /// File the current array with new values specified by the method parameter `value: [u8; N]`.
+    /// Returns [MemSecurityErr::CapacityExceeded] if the array already has elements since the values would not fit.
+    pub fn fill_from_slice(&mut self, value: [T; N]) -> MemSecurityResult<&mut Self> {
+        if self.0.try_extend_from_slice(&value).is_err() {
+            return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+        }
 
-        self
+        Ok(self)
     }
 
     /// Expose the internal as an owned array
@@ -516,8 +1524,9 @@ where
 
     /// Expose the internal as an owned array
     #[cfg(feature = "clonable_mem")]
+    #[deprecated(note = "use the `Clone` trait implementation instead")]
     pub fn clone_inner(&self) -> ZeroizeArrayVec<N, T> {
-        Self(self.0.clone())
+        self.clone()
     }
 
     /// Own this array
@@ -525,21 +1534,134 @@ where
         self
     }
 
-    /// Insert a value in the array after the last index
-    pub fn push(&mut self, value: T) -> &mut Self {
-        self.0.push(value);
+    /// Insert a value in the array after the last index.
+    /// Returns [MemSecurityErr::CapacityExceeded] if the array is full.
+    pub fn push(&mut self, value: T) -> MemSecurityResult<&mut Self> {
+        if self.0.try_push(value).is_err() {
+            return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+        }
+
+        Ok(self)
+    }
+
+    /// Insert a value at index specified in the array.
+    /// Returns [MemSecurityErr::IndexOutOfBounds] if `index` is greater than the length of the array
+    /// or [MemSecurityErr::CapacityExceeded] if the array is full.
+    pub fn insert(&mut self, index: usize, value: T) -> MemSecurityResult<&mut Self> {
+        if index > self.0.len() {
+            return Err(MemSecurityErr::IndexOutOfBounds {
+                index,
+                len: self.0.len(),
+            });
+        }
+
+        if self.0.try_insert(index, value).is_err() {
+            return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+        }
+
+        Ok(self)
+    }
+
+    /// Borrow the elements of the array as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Get the element at the index specified returning `None` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.0.get(index)
+    }
+
+    /// Get the first element returning `None` if the array is empty
+    pub fn first(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    /// Get the last element returning `None` if the array is empty
+    pub fn last(&self) -> Option<&T> {
+        self.0.last()
+    }
+
+    /// Remove every element and overwrite every slot of the backing array with zeroes,
+    /// including the slots of elements that were removed earlier. [Zeroize::zeroize] does the same.
+    pub fn clear_and_wipe(&mut self) -> &mut Self {
+        clear_and_wipe_array_vec(&mut self.0);
 
         self
     }
+}
+
+impl<const N: usize, T: fmt::Debug + ToBlake3Hash + Copy + Zeroize> ZeroizeArrayVec<N, T> {
+    /// Remove every element from the front yielding each one and wiping its slot in the array as it is yielded.
+    /// The slots that were not yielded are wiped when the iterator is dropped and the array is left empty.
+    ///
+    /// Since `T: Copy` the yielded values are copies, the caller is responsible for zeroizing them after use.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        ZeroizeArrayVecDrain {
+            inner: &mut self.0,
+            index: 0,
+        }
+    }
 
-    /// Insert a value at index specified in the array
-    pub fn insert(&mut self, index: usize, value: T) -> &mut Self {
-        self.0.insert(index, value);
+    /// Keep only the elements for which `f` returns `true`, preserving their order.
+    /// The kept elements are moved to the front and the slots left behind by the removed elements are wiped
+    /// before the length is shortened so no copy of a removed element remains in the backing array.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) -> &mut Self {
+        let mut kept = 0usize;
+
+        for index in 0..self.0.len() {
+            if f(&self.0[index]) {
+                if kept != index {
+                    self.0[kept] = self.0[index];
+                }
+
+                kept += 1;
+            }
+        }
+
+        self.0[kept..].iter_mut().for_each(|slot| slot.zeroize());
+        self.0.truncate(kept);
 
         self
     }
 }
 
+/// Iterator returned by [ZeroizeArrayVec::drain]
+struct ZeroizeArrayVecDrain<'a, const N: usize, T: Copy + Zeroize> {
+    inner: &'a mut ArrayVec<T, N>,
+    index: usize,
+}
+
+impl<const N: usize, T: Copy + Zeroize> Iterator for ZeroizeArrayVecDrain<'_, N, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = self.inner.get_mut(self.index)?;
+        let value = *slot;
+        slot.zeroize();
+
+        self.index += 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.inner.len() - self.index;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const N: usize, T: Copy + Zeroize> Drop for ZeroizeArrayVecDrain<'_, N, T> {
+    fn drop(&mut self) {
+        self.inner[self.index..]
+            .iter_mut()
+            .for_each(|slot| slot.zeroize());
+
+        self.inner.clear();
+    }
+}
+
 impl<const N: usize, T: fmt::Debug + ToBlake3Hash + Copy> Default for ZeroizeArrayVec<N, T> {
     fn default() -> Self {
         ZeroizeArrayVec::new()
@@ -577,9 +1699,16 @@ impl<const N: usize, T: fmt::Debug + ToBlake3Hash> fmt::Debug for ZeroizeArrayVe
 
 impl<const N: usize, T: fmt::Debug + ToBlake3Hash> Eq for ZeroizeArrayVec<N, T> {}
 
+#[cfg(feature = "clonable_mem")]
+impl<const N: usize, T: fmt::Debug + ToBlake3Hash + Clone> Clone for ZeroizeArrayVec<N, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<const N: usize, T: fmt::Debug + ToBlake3Hash> Zeroize for ZeroizeArrayVec<N, T> {
     fn zeroize(&mut self) {
-        self.0.clear()
+        clear_and_wipe_array_vec(&mut self.0)
     }
 }
 
@@ -606,19 +1735,27 @@ impl<const N: usize> ZeroizeArrayVecBytes<N> {
         ZeroizeArrayVecBytes(ArrayVec::<u8, N>::new())
     }
 
+    /// Remove every byte and overwrite every slot of the backing array with zeroes,
+    /// including the slots of bytes that were removed earlier. [Zeroize::zeroize] does the same.
+    pub fn clear_and_wipe(&mut self) -> &mut Self {
+        clear_and_wipe_array_vec(&mut self.0);
+
+        self
+    }
+
     /// Initialize a ZeroizeArray with the value of specified by the array of bytes
     pub fn new_with(value: [u8; N]) -> Self {
-        let mut outcome = ArrayVec::<u8, N>::new();
-        outcome.try_extend_from_slice(&value).unwrap(); // Should never fail due to const size constraints
-
-        ZeroizeArrayVecBytes(outcome)
+        ZeroizeArrayVecBytes(ArrayVec::from(value))
     }
 
-    /// File the current array with new values specified by the method parameter `value: [u8; N]`
-    pub fn fill_from_slice(&mut self, value: [u8; N]) -> &mut Self {
-        self.0.try_extend_from_slice(&value).unwrap(); // Should never fail due to const size constraints
+    /// File the current array with new values specified by the method parameter `value: [u8; N]`.
+    /// Returns [MemSecurityErr::CapacityExceeded] if the array already has elements since the values would not fit.
+    pub fn fill_from_slice(&mut self, value: [u8; N]) -> MemSecurityResult<&mut Self> {
+        if self.0.try_extend_from_slice(&value).is_err() {
+            return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+        }
 
-        self
+        Ok(self)
     }
 
     /// Expose the internal as an owned array
@@ -634,8 +1771,9 @@ impl<const N: usize> ZeroizeArrayVecBytes<N> {
 
     /// Expose the internal as an owned array
     #[cfg(feature = "clonable_mem")]
+    #[deprecated(note = "use the `Clone` trait implementation instead")]
     pub fn clone_inner(&self) -> ZeroizeArrayVecBytes<N> {
-        Self(self.0.clone())
+        self.clone()
     }
 
     /// Own this array
@@ -643,18 +1781,32 @@ impl<const N: usize> ZeroizeArrayVecBytes<N> {
         self
     }
 
-    /// Insert a value in the array after the last index
-    pub fn push(&mut self, value: u8) -> &mut Self {
-        self.0.push(value);
+    /// Insert a value in the array after the last index.
+    /// Returns [MemSecurityErr::CapacityExceeded] if the array is full.
+    pub fn push(&mut self, value: u8) -> MemSecurityResult<&mut Self> {
+        if self.0.try_push(value).is_err() {
+            return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+        }
 
-        self
+        Ok(self)
     }
 
-    /// Insert a value at index specified in the array
-    pub fn insert(&mut self, index: usize, value: u8) -> &mut Self {
-        self.0.insert(index, value);
+    /// Insert a value at index specified in the array.
+    /// Returns [MemSecurityErr::IndexOutOfBounds] if `index` is greater than the length of the array
+    /// or [MemSecurityErr::CapacityExceeded] if the array is full.
+    pub fn insert(&mut self, index: usize, value: u8) -> MemSecurityResult<&mut Self> {
+        if index > self.0.len() {
+            return Err(MemSecurityErr::IndexOutOfBounds {
+                index,
+                len: self.0.len(),
+            });
+        }
 
-        self
+        if self.0.try_insert(index, value).is_err() {
+            return Err(MemSecurityErr::CapacityExceeded { capacity: N });
+        }
+
+        Ok(self)
     }
 }
 
@@ -672,9 +1824,16 @@ impl<const N: usize> PartialEq for ZeroizeArrayVecBytes<N> {
 
 impl<const N: usize> Eq for ZeroizeArrayVecBytes<N> {}
 
+#[cfg(feature = "clonable_mem")]
+impl<const N: usize> Clone for ZeroizeArrayVecBytes<N> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<const N: usize> Zeroize for ZeroizeArrayVecBytes<N> {
     fn zeroize(&mut self) {
-        self.0.clear()
+        clear_and_wipe_array_vec(&mut self.0)
     }
 }
 
@@ -695,3 +1854,108 @@ impl<const N: usize> fmt::Debug for ZeroizeArrayVecBytes<N> {
         )
     }
 }
+
+/// The placeholder emitted instead of the secret bytes when a zeroizing type is serialized using `serde`
+#[cfg(feature = "serde")]
+pub const REDACTED_PLACEHOLDER: &str = "<redacted>";
+
+/// A wrapper used to serialize the actual bytes of a zeroizing type using `serde`
+/// for the rare case where the secret must be serialized, for example when writing it to a key store.
+/// The zeroizing types otherwise serialize as [REDACTED_PLACEHOLDER].
+/// #### Structure
+/// ```rs
+/// pub struct RevealSerialize<T: SecretBytes>(pub T);
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct RevealSerialize<T: SecretBytes>(pub T);
+
+#[cfg(feature = "serde")]
+impl<T: SecretBytes> serde::Serialize for RevealSerialize<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0.expose())
+    }
+}
+
+/// Copy `bytes` into an exactly sized allocation owned by a [secrecy::SecretBox] so no spare capacity is left behind
+#[cfg(feature = "secrecy")]
+fn boxed_secret(bytes: &[u8]) -> secrecy::SecretSlice<u8> {
+    secrecy::SecretBox::new(Box::from(bytes))
+}
+
+#[cfg(feature = "secrecy")]
+impl<const N: usize> secrecy::ExposeSecret<[u8]> for ZeroizeArray<N> {
+    fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl secrecy::ExposeSecret<[u8]> for ZeroizeBytes {
+    fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The bytes are copied into an exactly sized [secrecy::SecretBox] and the array is wiped
+#[cfg(feature = "secrecy")]
+impl<const N: usize> From<ZeroizeArray<N>> for secrecy::SecretSlice<u8> {
+    fn from(mut value: ZeroizeArray<N>) -> Self {
+        let outcome = boxed_secret(&value.0);
+        value.wipe();
+
+        outcome
+    }
+}
+
+/// The allocation of the [ZeroizeBytes] is moved into the [secrecy::SecretBox] when it holds exactly the bytes.
+/// A locked allocation or one with spare capacity is instead copied into an exactly sized allocation
+/// and the whole old allocation is wiped, so no unwiped spare capacity is handed to the [secrecy::SecretBox].
+#[cfg(feature = "secrecy")]
+impl From<ZeroizeBytes> for secrecy::SecretSlice<u8> {
+    fn from(mut value: ZeroizeBytes) -> Self {
+        if value.is_locked() || value.0.capacity() != value.0.len() {
+            let outcome = boxed_secret(&value.0);
+            value.zeroize();
+
+            return outcome;
+        }
+
+        let mut moved = Vec::from(core::mem::take(&mut value.0));
+
+        if moved.capacity() != moved.len() {
+            // `BytesMut` shifted the bytes to the start of a larger allocation so copy them out and wipe it
+            let outcome = boxed_secret(&moved);
+            moved.zeroize();
+
+            return outcome;
+        }
+
+        secrecy::SecretBox::new(moved.into_boxed_slice())
+    }
+}
+
+/// The bytes are copied out of the [secrecy::SecretBox] since it cannot release its allocation.
+/// The [secrecy::SecretBox] is dropped afterwards which zeroizes its allocation.
+#[cfg(feature = "secrecy")]
+impl From<secrecy::SecretSlice<u8>> for ZeroizeBytes {
+    fn from(value: secrecy::SecretSlice<u8>) -> Self {
+        use secrecy::ExposeSecret;
+
+        ZeroizeBytes::new_with_data(value.expose_secret())
+    }
+}
+
+/// The bytes are copied out of the [secrecy::SecretBox] since it cannot release its allocation.
+/// The [secrecy::SecretBox] is dropped afterwards which zeroizes its allocation.
+/// Returns [MemSecurityErr::InvalidSliceLength] if the secret is not `N` bytes long.
+#[cfg(feature = "secrecy")]
+impl<const N: usize> TryFrom<secrecy::SecretSlice<u8>> for ZeroizeArray<N> {
+    type Error = MemSecurityErr;
+
+    fn try_from(value: secrecy::SecretSlice<u8>) -> MemSecurityResult<Self> {
+        use secrecy::ExposeSecret;
+
+        ZeroizeArray::new_from_slice(value.expose_secret())
+    }
+}
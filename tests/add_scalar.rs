@@ -0,0 +1,44 @@
+//! `ZeroizeArray::add_scalar` adds to a big-endian counter with carry and refuses to wrap.
+
+use memsecurity::{MemSecurityErr, ZeroizeArray};
+
+#[test]
+fn carry_propagates_across_bytes() {
+    let mut counter = ZeroizeArray::new([0x00, 0x00, 0xFF, 0xFF]);
+
+    counter.add_scalar(1).unwrap();
+    assert_eq!(counter.expose_borrowed(), &[0x00, 0x01, 0x00, 0x00]);
+
+    counter.add_scalar(0x0102_0304).unwrap();
+    assert_eq!(counter.expose_borrowed(), &[0x01, 0x03, 0x03, 0x04]);
+}
+
+#[test]
+fn wide_counters_accept_the_full_scalar() {
+    let mut counter = ZeroizeArray::new([0u8; 16]);
+
+    counter.add_scalar(u64::MAX).unwrap();
+    counter.add_scalar(1).unwrap();
+
+    let mut expected = [0u8; 16];
+    expected[7] = 1;
+    assert_eq!(counter.expose_borrowed(), &expected);
+}
+
+#[test]
+fn overflow_leaves_the_counter_unchanged() {
+    let mut counter = ZeroizeArray::new([0xFF, 0xFE]);
+
+    assert_eq!(
+        counter.add_scalar(2).err(),
+        Some(MemSecurityErr::CounterOverflow)
+    );
+    assert_eq!(
+        counter.add_scalar(0x1_0000).err(),
+        Some(MemSecurityErr::CounterOverflow)
+    );
+    assert_eq!(counter.expose_borrowed(), &[0xFF, 0xFE]);
+
+    counter.add_scalar(1).unwrap();
+    assert_eq!(counter.expose_borrowed(), &[0xFF, 0xFF]);
+}
@@ -0,0 +1,75 @@
+//! `push`, `insert` and `fill_from_slice` on the `ArrayVec` wrappers return errors instead of panicking
+//! when the values do not fit, and leave the array unchanged.
+
+use memsecurity::{
+    zeroize::Zeroize, MemSecurityErr, ToBlake3Hash, ZeroizeArrayVec, ZeroizeArrayVecBytes,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Secret(u32);
+
+impl ToBlake3Hash for Secret {
+    fn hash(&self) -> memsecurity::blake3::Hash {
+        memsecurity::blake3::hash(&self.0.to_le_bytes())
+    }
+}
+
+impl Zeroize for Secret {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[test]
+fn array_vec_reports_capacity_errors() {
+    let mut items = ZeroizeArrayVec::<2, Secret>::new();
+    items.push(Secret(1)).unwrap().insert(0, Secret(0)).unwrap();
+
+    assert_eq!(
+        items.push(Secret(2)).err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(
+        items.insert(1, Secret(2)).err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(
+        items.fill_from_slice([Secret(2), Secret(3)]).err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(items.as_slice(), [Secret(0), Secret(1)]);
+
+    let mut items = ZeroizeArrayVec::<2, Secret>::new();
+    assert_eq!(
+        items.insert(1, Secret(0)).err(),
+        Some(MemSecurityErr::IndexOutOfBounds { index: 1, len: 0 })
+    );
+    assert!(items.fill_from_slice([Secret(4), Secret(5)]).is_ok());
+    assert_eq!(items.as_slice(), [Secret(4), Secret(5)]);
+}
+
+#[test]
+fn array_vec_bytes_reports_capacity_errors() {
+    let mut bytes = ZeroizeArrayVecBytes::<2>::new();
+    bytes.push(1).unwrap().insert(0, 0).unwrap();
+
+    assert_eq!(
+        bytes.push(2).err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(
+        bytes.insert(0, 2).err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(
+        bytes.fill_from_slice([2, 3]).err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(bytes.expose_borrowed().as_slice(), [0, 1]);
+
+    let mut bytes = ZeroizeArrayVecBytes::<2>::new();
+    assert_eq!(
+        bytes.insert(3, 0).err(),
+        Some(MemSecurityErr::IndexOutOfBounds { index: 3, len: 0 })
+    );
+}
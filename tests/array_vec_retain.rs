@@ -0,0 +1,57 @@
+//! `ZeroizeArrayVec::retain` must wipe the slots of the removed elements which `ArrayVec::retain`
+//! leaves untouched in the backing array.
+
+use memsecurity::{zeroize::Zeroize, ToBlake3Hash, ZeroizeArrayVec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Secret(u32);
+
+impl ToBlake3Hash for Secret {
+    fn hash(&self) -> memsecurity::blake3::Hash {
+        memsecurity::blake3::hash(&self.0.to_le_bytes())
+    }
+}
+
+impl Zeroize for Secret {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[test]
+fn retain_keeps_matching_elements_in_order() {
+    let mut items = ZeroizeArrayVec::<5, Secret>::new_with([
+        Secret(1),
+        Secret(2),
+        Secret(3),
+        Secret(4),
+        Secret(5),
+    ]);
+
+    items.retain(|item| item.0 % 2 == 1);
+
+    assert_eq!(items.as_slice(), &[Secret(1), Secret(3), Secret(5)]);
+}
+
+#[test]
+fn retain_wipes_the_slots_of_removed_elements() {
+    let mut items = ZeroizeArrayVec::<4, Secret>::new_with([
+        Secret(0xAAAA_AAAA),
+        Secret(0xBBBB_BBBB),
+        Secret(0xCCCC_CCCC),
+        Secret(0xDDDD_DDDD),
+    ]);
+
+    items.retain(|item| *item == Secret(0xCCCC_CCCC));
+    assert_eq!(items.expose_borrowed().len(), 1);
+
+    // SAFETY: every slot of the backing array was initialized by `new_with` and `retain`
+    // only overwrites them, so all `4` slots are still initialized `Secret` values.
+    #[allow(unsafe_code)]
+    let backing = unsafe { core::slice::from_raw_parts(items.expose_borrowed().as_ptr(), 4) };
+
+    assert_eq!(
+        backing,
+        &[Secret(0xCCCC_CCCC), Secret(0), Secret(0), Secret(0)]
+    );
+}
@@ -0,0 +1,46 @@
+//! Zeroizing a `ZeroizeArrayVec` or a `ZeroizeArrayVecBytes` must overwrite the backing array,
+//! not only reset the length as `ArrayVec::clear` does.
+
+use memsecurity::{zeroize::Zeroize, ToBlake3Hash, ZeroizeArrayVec, ZeroizeArrayVecBytes};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Secret(u32);
+
+impl ToBlake3Hash for Secret {
+    fn hash(&self) -> memsecurity::blake3::Hash {
+        memsecurity::blake3::hash(&self.0.to_le_bytes())
+    }
+}
+
+#[test]
+fn zeroize_wipes_the_backing_array_of_elements() {
+    let mut items = ZeroizeArrayVec::<3, Secret>::new_with([
+        Secret(0xAAAA_AAAA),
+        Secret(0xBBBB_BBBB),
+        Secret(0xCCCC_CCCC),
+    ]);
+
+    items.zeroize();
+    assert!(items.as_slice().is_empty());
+
+    // SAFETY: every slot of the backing array was initialized by `new_with` and then overwritten
+    // with zeroes, which is a valid `Secret`, so reading all `3` slots reads initialized values.
+    #[allow(unsafe_code)]
+    let backing = unsafe { core::slice::from_raw_parts(items.expose_borrowed().as_ptr(), 3) };
+
+    assert_eq!(backing, &[Secret(0); 3]);
+}
+
+#[test]
+fn clear_and_wipe_wipes_the_backing_array_of_bytes() {
+    let mut bytes = ZeroizeArrayVecBytes::<8>::new_with([0xAA; 8]);
+
+    bytes.clear_and_wipe();
+    assert!(bytes.expose_borrowed().is_empty());
+
+    // SAFETY: every slot of the backing array was initialized by `new_with` and then overwritten with zeroes
+    #[allow(unsafe_code)]
+    let backing = unsafe { core::slice::from_raw_parts(bytes.expose_borrowed().as_ptr(), 8) };
+
+    assert_eq!(backing, &[0u8; 8]);
+}
@@ -0,0 +1,87 @@
+//! A message hashed incrementally with `blake3` and signed with `sign_prehashed` must verify against the
+//! same prehash and context and be rejected for any other context. The signature is Ed25519ph over
+//! `SHA-512(blake3 prehash)` with the crate's domain label prepended to the context.
+
+use memsecurity::{
+    ed25519_dalek::{Digest, Sha512},
+    EncryptedMem, MemSecurityErr, BLAKE3_PREHASH_SIGNATURE_LABEL,
+};
+
+fn streamed_prehash() -> memsecurity::blake3::Hash {
+    let mut hasher = memsecurity::blake3::Hasher::new();
+    (0..64u8).for_each(|chunk| {
+        hasher.update(&[chunk; 1024]);
+    });
+
+    hasher.finalize()
+}
+
+#[test]
+fn prehashed_signature_verifies() {
+    let mut sealed = EncryptedMem::new();
+    let verifying_key = sealed.generate_ed25519().unwrap();
+
+    let signature = sealed
+        .sign_prehashed(b"memsecurity-test", streamed_prehash())
+        .unwrap();
+
+    assert_eq!(
+        EncryptedMem::verify_prehashed(
+            &verifying_key,
+            b"memsecurity-test",
+            streamed_prehash(),
+            &signature
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn prehashed_signature_is_bound_to_its_context() {
+    let mut sealed = EncryptedMem::new();
+    let verifying_key = sealed.generate_ed25519().unwrap();
+
+    let signature = sealed
+        .sign_prehashed(b"memsecurity-test", streamed_prehash())
+        .unwrap();
+
+    assert_eq!(
+        EncryptedMem::verify_prehashed(
+            &verifying_key,
+            b"another-context",
+            streamed_prehash(),
+            &signature
+        ),
+        Err(MemSecurityErr::SignatureError)
+    );
+
+    let max_context = 255 - BLAKE3_PREHASH_SIGNATURE_LABEL.len();
+    assert!(sealed
+        .sign_prehashed(&vec![0u8; max_context], streamed_prehash())
+        .is_ok());
+    assert_eq!(
+        sealed.sign_prehashed(&vec![0u8; max_context + 1], streamed_prehash()),
+        Err(MemSecurityErr::SignatureError)
+    );
+}
+
+#[test]
+fn prehashed_signature_uses_the_labelled_construction() {
+    let mut sealed = EncryptedMem::new();
+    let verifying_key = sealed.generate_ed25519().unwrap();
+
+    let signature = sealed
+        .sign_prehashed(b"memsecurity-test", streamed_prehash())
+        .unwrap();
+
+    let digest = || Sha512::new().chain_update(streamed_prehash().as_bytes());
+    let labelled = [BLAKE3_PREHASH_SIGNATURE_LABEL, b"memsecurity-test"].concat();
+
+    assert!(verifying_key
+        .verify_prehashed_strict(digest(), Some(&labelled), &signature)
+        .is_ok());
+    // Without the label the signature is not a plain Ed25519ph signature over the same context
+    assert!(verifying_key
+        .verify_prehashed_strict(digest(), Some(b"memsecurity-test"), &signature)
+        .is_err());
+}
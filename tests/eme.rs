@@ -0,0 +1,79 @@
+//! `EncryptedMem::encrypt_eme` appends a keyed Blake3 MAC over the nonce and ciphertext which
+//! `EncryptedMem::decrypt_eme` verifies before the AEAD is used.
+
+use memsecurity::{EncryptedMem, MemSecurityErr, ZeroizeBytes};
+
+/// The length of the `version || nonce || ciphertext length` header written by `to_bytes`
+const HEADER_LEN: usize = 1 + 16 + 8;
+
+fn sealed_eme_bytes() -> Vec<u8> {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt_eme(&ZeroizeBytes::new_with_data(b"defense in depth"))
+        .unwrap();
+
+    sealed.to_bytes().expose_borrowed().to_vec()
+}
+
+fn decrypt_eme(bytes: &[u8]) -> Result<ZeroizeBytes, MemSecurityErr> {
+    EncryptedMem::from_bytes(bytes).unwrap().decrypt_eme()
+}
+
+#[test]
+fn eme_round_trips() {
+    let bytes = sealed_eme_bytes();
+
+    // Plaintext, Ascon128a tag and Blake3 MAC
+    assert_eq!(bytes.len(), HEADER_LEN + 16 + 16 + 32);
+    assert_eq!(
+        &decrypt_eme(&bytes).unwrap().expose_borrowed()[..],
+        b"defense in depth"
+    );
+}
+
+#[test]
+fn tampering_with_any_part_fails_the_mac() {
+    let bytes = sealed_eme_bytes();
+
+    // The nonce, the first ciphertext byte, the last tag byte and the last MAC byte
+    [1, HEADER_LEN, bytes.len() - 33, bytes.len() - 1]
+        .into_iter()
+        .for_each(|index| {
+            let mut tampered = bytes.clone();
+            tampered[index] ^= 1;
+
+            assert_eq!(
+                decrypt_eme(&tampered).err(),
+                Some(MemSecurityErr::AuthenticationFailed),
+                "byte {index}"
+            );
+        });
+}
+
+#[test]
+fn eme_ciphertext_is_not_a_plain_ciphertext() {
+    let sealed = EncryptedMem::from_bytes(&sealed_eme_bytes()).unwrap();
+
+    assert_eq!(
+        sealed.decrypt().err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+
+    let mut plain = EncryptedMem::new();
+    plain
+        .encrypt(&ZeroizeBytes::new_with_data(
+            b"a plain secret long enough to hold a MAC",
+        ))
+        .unwrap();
+    assert_eq!(
+        plain.decrypt_eme().err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+
+    let mut short = EncryptedMem::new();
+    short.encrypt(&ZeroizeBytes::new()).unwrap();
+    assert_eq!(
+        short.decrypt_eme().err(),
+        Some(MemSecurityErr::CiphertextTooShort { min: 48, found: 16 })
+    );
+}
@@ -0,0 +1,66 @@
+//! `EncryptedMem::encrypt_into` writes `nonce || ciphertext || tag` into a caller provided buffer
+//! which `EncryptedMem::decrypt_from` opens under the same context and associated data.
+
+use memsecurity::{
+    EncryptedMem, EncryptedMemBuilder, MemSecurityErr, ZeroizeBytes, ZeroizeBytesArray,
+};
+
+#[test]
+fn encrypt_into_round_trips_through_decrypt_from() {
+    let sealer = EncryptedMemBuilder::new()
+        .aad(b"record-7")
+        .context("tenant-a")
+        .build()
+        .unwrap();
+
+    let mut first = ZeroizeBytesArray::<64>::new();
+    let mut second = ZeroizeBytesArray::<64>::new();
+    sealer
+        .encrypt_into(&ZeroizeBytes::new_with_data(b"buffered"), &mut first)
+        .unwrap();
+    sealer
+        .encrypt_into(&ZeroizeBytes::new_with_data(b"buffered"), &mut second)
+        .unwrap();
+
+    // Every call draws its own nonce
+    assert_ne!(
+        &first.expose_borrowed()[..16],
+        &second.expose_borrowed()[..16]
+    );
+    assert_eq!(
+        &sealer.decrypt_from(&first).unwrap().expose_borrowed()[..],
+        b"buffered"
+    );
+    assert_eq!(
+        &sealer.decrypt_from(&second).unwrap().expose_borrowed()[..],
+        b"buffered"
+    );
+
+    // Another context cannot open it
+    assert_eq!(
+        EncryptedMem::with_context("tenant-b")
+            .decrypt_from(&first)
+            .err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn decrypt_from_rejects_short_and_small_buffers() {
+    let sealer = EncryptedMem::new();
+
+    assert_eq!(
+        sealer
+            .decrypt_from(&ZeroizeBytesArray::<31>::new_zeroed())
+            .err(),
+        Some(MemSecurityErr::CiphertextTooShort { min: 32, found: 31 })
+    );
+
+    let mut out = ZeroizeBytesArray::<40>::new();
+    assert_eq!(
+        sealer
+            .encrypt_into(&ZeroizeBytes::new_with_data(b"too long to fit"), &mut out)
+            .err(),
+        Some(MemSecurityErr::CapacityExceeded { capacity: 40 })
+    );
+}
@@ -0,0 +1,63 @@
+//! `EncryptedMemBuilder` seals under the configured nonce, associated data and context and rejects
+//! a nonce whose length does not match the cipher.
+
+use memsecurity::{CipherChoice, EncryptedMem, EncryptedMemBuilder, MemSecurityErr, ZeroizeBytes};
+
+#[test]
+fn builder_configures_nonce_aad_and_context() {
+    let nonce = [9u8; 16];
+
+    let mut sealed = EncryptedMemBuilder::new()
+        .cipher(CipherChoice::Ascon128a)
+        .aad(b"record-42")
+        .nonce(Some(&nonce))
+        .context("tenant-a")
+        .build()
+        .unwrap();
+
+    assert_eq!(sealed.nonce().expose_borrowed(), &nonce);
+    assert_eq!(sealed.aad(), b"record-42");
+    assert_eq!(sealed.context(), Some("tenant-a"));
+
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"builder secret"))
+        .unwrap();
+
+    assert_eq!(sealed.nonce().expose_borrowed(), &nonce);
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"builder secret"
+    );
+}
+
+#[test]
+fn associated_data_is_authenticated() {
+    let mut sealed = EncryptedMemBuilder::new()
+        .aad(b"record-42")
+        .build()
+        .unwrap();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"builder secret"))
+        .unwrap();
+
+    // The serialized form carries neither the context nor the associated data
+    let without_aad = EncryptedMem::from_bytes(sealed.to_bytes().expose_borrowed()).unwrap();
+
+    assert_eq!(
+        without_aad.decrypt().err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn nonce_of_the_wrong_length_is_rejected() {
+    let outcome = EncryptedMemBuilder::new().nonce(Some(&[0u8; 24])).build();
+
+    assert_eq!(
+        outcome.err(),
+        Some(MemSecurityErr::InvalidSliceLength {
+            expected: 16,
+            found: 24
+        })
+    );
+}
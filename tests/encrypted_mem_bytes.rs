@@ -0,0 +1,100 @@
+//! `EncryptedMem::to_bytes` frames a sealed value as `version || nonce || ciphertext length || ciphertext`
+//! and `EncryptedMem::from_bytes` rejects any buffer that is not exactly one such frame.
+
+use memsecurity::{EncryptedMem, MemSecurityErr, ZeroizeBytes, ENCRYPTED_MEM_BYTES_VERSION};
+
+const HEADER_LEN: usize = 1 + 16 + 8;
+
+fn sealed_bytes() -> Vec<u8> {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"over the channel"))
+        .unwrap();
+
+    sealed.to_bytes().expose_borrowed().to_vec()
+}
+
+#[test]
+fn bytes_round_trip() {
+    let bytes = sealed_bytes();
+    assert_eq!(bytes.len(), HEADER_LEN + 16 + 16);
+    assert_eq!(bytes[0], ENCRYPTED_MEM_BYTES_VERSION);
+
+    let parsed = EncryptedMem::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        &parsed.decrypt().unwrap().expose_borrowed()[..],
+        b"over the channel"
+    );
+    assert_eq!(parsed.to_bytes().expose_borrowed(), &bytes[..]);
+}
+
+#[test]
+fn truncated_bytes_are_rejected() {
+    let bytes = sealed_bytes();
+
+    (0..HEADER_LEN).for_each(|len| {
+        assert_eq!(
+            EncryptedMem::from_bytes(&bytes[..len]).err(),
+            Some(MemSecurityErr::InvalidSliceLength {
+                expected: HEADER_LEN,
+                found: len
+            })
+        );
+    });
+
+    (HEADER_LEN..bytes.len()).for_each(|len| {
+        assert_eq!(
+            EncryptedMem::from_bytes(&bytes[..len]).err(),
+            Some(MemSecurityErr::InvalidSliceLength {
+                expected: bytes.len(),
+                found: len
+            })
+        );
+    });
+}
+
+#[test]
+fn trailing_bytes_are_rejected() {
+    let mut bytes = sealed_bytes();
+    bytes.push(0);
+
+    assert_eq!(
+        EncryptedMem::from_bytes(&bytes).err(),
+        Some(MemSecurityErr::InvalidSliceLength {
+            expected: bytes.len() - 1,
+            found: bytes.len()
+        })
+    );
+}
+
+#[test]
+fn unknown_versions_are_rejected() {
+    let mut bytes = sealed_bytes();
+    bytes[0] = ENCRYPTED_MEM_BYTES_VERSION + 1;
+
+    assert_eq!(
+        EncryptedMem::from_bytes(&bytes).err(),
+        Some(MemSecurityErr::UnsupportedVersion {
+            found: ENCRYPTED_MEM_BYTES_VERSION + 1
+        })
+    );
+}
+
+#[test]
+fn ciphertext_shorter_than_the_tag_is_rejected() {
+    let mut bytes = sealed_bytes();
+    bytes.truncate(HEADER_LEN + 15);
+    bytes[17..HEADER_LEN].copy_from_slice(&15u64.to_le_bytes());
+
+    assert_eq!(
+        EncryptedMem::from_bytes(&bytes).err(),
+        Some(MemSecurityErr::CiphertextTooShort { min: 16, found: 15 })
+    );
+
+    // A length prefix that does not fit in memory is reported without overflowing
+    bytes[17..HEADER_LEN].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(
+        EncryptedMem::from_bytes(&bytes),
+        Err(MemSecurityErr::InvalidSliceLength { .. })
+    ));
+}
@@ -0,0 +1,118 @@
+//! Encryption failures must surface as `EncryptionFailed` and every decryption path must report a tag
+//! mismatch as `AuthenticationFailed`, separately from a plaintext of an unexpected length.
+
+use memsecurity::{EncryptedMem, MemSecurityErr, ZeroizeBytes};
+
+fn tampered() -> EncryptedMem {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"tamper with me"))
+        .unwrap();
+
+    let mut bytes = sealed.to_bytes().expose_borrowed().to_vec();
+    // Flip a bit of the first ciphertext byte which follows the `version || nonce || length` header
+    bytes[25] ^= 1;
+
+    EncryptedMem::from_bytes(&bytes).unwrap()
+}
+
+#[test]
+fn tampered_ciphertext_fails_authentication() {
+    let sealed = tampered();
+
+    assert_eq!(
+        sealed.decrypt().err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+    assert_eq!(
+        sealed.decrypt_zeroizing().err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn tampered_detached_ciphertext_fails_authentication() {
+    let (nonce, ciphertext) =
+        EncryptedMem::seal_detached(&ZeroizeBytes::new_with_data(b"tamper with me")).unwrap();
+
+    let tampered: ZeroizeBytes = ciphertext
+        .expose_borrowed()
+        .iter()
+        .enumerate()
+        .map(|(index, byte)| if index == 0 { byte ^ 1 } else { *byte })
+        .collect();
+
+    assert_eq!(
+        EncryptedMem::open_detached(&nonce, &tampered).err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn typed_decryptions_fail_authentication_on_the_expected_length() {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(&[7u8; 32]))
+        .unwrap();
+
+    let mut bytes = sealed.to_bytes().expose_borrowed().to_vec();
+    bytes[25] ^= 1;
+    let tampered = EncryptedMem::from_bytes(&bytes).unwrap();
+
+    assert_eq!(
+        tampered.decrypt_32byte().err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+    assert_eq!(
+        tampered.verify_and_decrypt(32).err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+    assert_eq!(
+        tampered.decrypt_padded(16).err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn verify_and_decrypt_checks_the_length_first() {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"exactly 17 bytes!"))
+        .unwrap();
+
+    assert_eq!(
+        &sealed.verify_and_decrypt(17).unwrap().expose_borrowed()[..],
+        &b"exactly 17 bytes!"[..]
+    );
+    assert_eq!(
+        sealed.verify_and_decrypt(16).err(),
+        Some(MemSecurityErr::UnexpectedPlaintextLength {
+            expected: 16,
+            found: 17
+        })
+    );
+    // The length is rejected even when the tag was tampered with
+    assert_eq!(
+        tampered().verify_and_decrypt(0).err(),
+        Some(MemSecurityErr::UnexpectedPlaintextLength {
+            expected: 0,
+            found: 14
+        })
+    );
+}
+
+#[test]
+fn truncated_ciphertext_is_too_short() {
+    let mut sealed = EncryptedMem::new();
+    sealed.encrypt(&ZeroizeBytes::new()).unwrap();
+
+    let mut bytes = sealed.to_bytes().expose_borrowed().to_vec();
+    // Drop the last byte of the tag and shorten the `u64` length prefix to match
+    bytes.pop();
+    bytes[17] = 15;
+
+    assert_eq!(
+        EncryptedMem::from_bytes(&bytes).err(),
+        Some(MemSecurityErr::CiphertextTooShort { min: 16, found: 15 })
+    );
+}
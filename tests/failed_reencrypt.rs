@@ -0,0 +1,28 @@
+//! A failed encryption into an `EncryptedMem` that already holds a secret must leave the previous
+//! nonce and ciphertext in place so the previous secret can still be decrypted.
+
+use memsecurity::{argon2, EncryptedMem, MemSecurityErr, ZeroizeBytes};
+
+#[test]
+fn failed_reencrypt_keeps_the_previous_secret() {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"previous secret"))
+        .unwrap();
+    let nonce = *sealed.nonce().expose_borrowed();
+
+    // Argon2 rejects a salt shorter than 8 bytes
+    let outcome = sealed.encrypt_password_derived(
+        &ZeroizeBytes::new_with_data(b"next secret"),
+        &ZeroizeBytes::new_with_data(b"password"),
+        b"short",
+        argon2::Params::default(),
+    );
+
+    assert_eq!(outcome.err(), Some(MemSecurityErr::PasswordDerivationError));
+    assert_eq!(sealed.nonce().expose_borrowed(), &nonce);
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"previous secret"
+    );
+}
@@ -0,0 +1,24 @@
+//! The key encryption key derived from a seeded vault must not depend on whether the pages are hashed
+//! sequentially or in parallel. Run this test both with and without the `rayon` feature, for example
+//! `cargo test --test kek_derivation --features rayon`, the ciphertext must match in both builds.
+
+use memsecurity::{
+    blake3, init_sealing_key_from_seed, EncryptedMem, ZeroizeBytes, COUNTER_NONCE_PREFIX_LEN,
+};
+
+#[test]
+fn seeded_kek_is_deterministic() {
+    init_sealing_key_from_seed(&ZeroizeBytes::new_with_data(&[7u8; 32])).unwrap();
+
+    let mut sealed = EncryptedMem::new_counter_nonce([0u8; COUNTER_NONCE_PREFIX_LEN], 1);
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"deterministic"))
+        .unwrap();
+
+    assert_eq!(
+        blake3::hash(sealed.ciphertext().expose_borrowed())
+            .to_hex()
+            .as_str(),
+        "151fa81cac66522c902b9cc6bee5da2d75205b7d66fe2f56de85ecbd760c38b8"
+    );
+}
@@ -0,0 +1,18 @@
+//! `ZeroizeBytes::keyed_hash` is a Blake3 MAC keyed with the caller supplied key.
+
+use memsecurity::{blake3, ZeroizeArray, ZeroizeBytes};
+
+#[test]
+fn keyed_hash_matches_blake3_keyed_hash() {
+    let key = ZeroizeArray::new([7u8; 32]);
+    let bytes = ZeroizeBytes::new_with_data(b"authenticate me");
+
+    assert_eq!(
+        bytes.keyed_hash(&key),
+        blake3::keyed_hash(&[7u8; 32], b"authenticate me")
+    );
+    assert_ne!(
+        bytes.keyed_hash(&key),
+        bytes.keyed_hash(&ZeroizeArray::new([8u8; 32]))
+    );
+}
@@ -0,0 +1,37 @@
+//! `ZeroizeBytes::new_locked_capacity` keeps its bytes in `mlock`ed memory when the buffer is moved to a
+//! new allocation by growing past its capacity or by shrinking it.
+
+use memsecurity::ZeroizeBytes;
+
+#[test]
+fn growing_and_shrinking_keeps_the_bytes_locked() {
+    let mut bytes = ZeroizeBytes::new_locked_capacity(16).unwrap();
+    assert!(bytes.is_locked());
+
+    bytes.extend_from_slice(&[7u8; 16]);
+    let capacity = bytes.expose_borrowed().capacity();
+    bytes.extend_from_slice(&[9u8; 64]);
+
+    assert!(bytes.expose_borrowed().capacity() > capacity);
+    assert!(bytes.is_locked());
+    assert_eq!(&bytes.expose_borrowed()[..16], &[7u8; 16]);
+    assert_eq!(&bytes.expose_borrowed()[16..], &[9u8; 64]);
+
+    bytes.wipe_and_shrink_to_fit(20);
+
+    assert!(bytes.is_locked());
+    assert_eq!(bytes.expose_borrowed().len(), 20);
+    assert_eq!(&bytes.expose_borrowed()[16..], &[9u8; 4]);
+
+    // An empty allocation has nothing left to lock
+    bytes.wipe_and_shrink_to_fit(0);
+    assert!(!bytes.is_locked());
+}
+
+#[test]
+fn unlocked_bytes_stay_unlocked_when_grown() {
+    let mut bytes = ZeroizeBytes::new_with_capacity(4);
+    bytes.extend_from_slice(&[1u8; 32]);
+
+    assert!(!bytes.is_locked());
+}
@@ -0,0 +1,89 @@
+//! Encrypting into the same `EncryptedMem` twice must not reuse the nonce of the first encryption,
+//! and a counter based nonce must not be silently replaced by a random one.
+
+use memsecurity::{
+    EncryptedMem, MemSecurityErr, ZeroizeBytes, ZeroizeBytesArray, COUNTER_NONCE_PREFIX_LEN,
+};
+
+#[test]
+fn encrypting_twice_rotates_the_nonce() {
+    let mut sealed = EncryptedMem::new();
+
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"first record"))
+        .unwrap();
+    let first_nonce = *sealed.nonce().expose_borrowed();
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"first record"
+    );
+
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"second record"))
+        .unwrap();
+    let second_nonce = *sealed.nonce().expose_borrowed();
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"second record"
+    );
+
+    assert_ne!(first_nonce, second_nonce);
+}
+
+#[test]
+fn encrypt_into_leaves_the_sealed_secret_untouched() {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"held secret"))
+        .unwrap();
+    let nonce = *sealed.nonce().expose_borrowed();
+
+    let mut out = ZeroizeBytesArray::<64>::new();
+    sealed
+        .encrypt_into(&ZeroizeBytes::new_with_data(b"other record"), &mut out)
+        .unwrap();
+
+    assert_eq!(sealed.nonce().expose_borrowed(), &nonce);
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"held secret"
+    );
+    // `nonce || ciphertext || tag`
+    assert_eq!(out.expose_borrowed().len(), 16 + 12 + 16);
+    assert_ne!(&out.expose_borrowed()[..16], &nonce);
+}
+
+#[test]
+fn counter_nonce_is_not_replaced_on_reencrypt() {
+    let mut sealed = EncryptedMem::new_counter_nonce([3u8; COUNTER_NONCE_PREFIX_LEN], 9);
+
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"counted record"))
+        .unwrap();
+    let nonce = *sealed.nonce().expose_borrowed();
+    assert_eq!(&nonce[COUNTER_NONCE_PREFIX_LEN..], &9u64.to_be_bytes());
+
+    assert_eq!(
+        sealed
+            .encrypt(&ZeroizeBytes::new_with_data(b"another record"))
+            .err(),
+        Some(MemSecurityErr::CounterNonceReused)
+    );
+    assert_eq!(
+        sealed.refresh_nonce().err(),
+        Some(MemSecurityErr::CounterNonceReused)
+    );
+
+    // The first encryption is left in place
+    assert_eq!(sealed.nonce().expose_borrowed(), &nonce);
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"counted record"
+    );
+
+    // The next counter is sealed by a new value
+    let mut next = EncryptedMem::new_counter_nonce([3u8; COUNTER_NONCE_PREFIX_LEN], 10);
+    next.encrypt(&ZeroizeBytes::new_with_data(b"another record"))
+        .unwrap();
+    assert_ne!(next.nonce().expose_borrowed(), &nonce);
+}
@@ -0,0 +1,97 @@
+//! `EncryptedMem::decrypt_padded` strips valid PKCS#7 padding and rejects a block size outside `1..=255`,
+//! padding bytes of zero or larger than the block and padding bytes that do not all match.
+
+use memsecurity::{EncryptedMem, MemSecurityErr, ZeroizeBytes};
+
+/// Seal `plaintext` without padding so malformed padding can be fed to `decrypt_padded`
+fn sealed_unpadded(plaintext: &[u8]) -> EncryptedMem {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(plaintext))
+        .unwrap();
+
+    sealed
+}
+
+#[test]
+fn valid_padding_is_stripped() {
+    [&b"hello"[..], b"", b"8 bytes!", b"longer than one block"]
+        .into_iter()
+        .for_each(|plaintext| {
+            let mut sealed = EncryptedMem::new();
+            sealed
+                .encrypt_padded(&ZeroizeBytes::new_with_data(plaintext), 8)
+                .unwrap();
+
+            // A plaintext filling whole blocks still gets a full block of padding
+            let padded_len = (plaintext.len() / 8 + 1) * 8;
+            assert_eq!(sealed.ciphertext().expose_borrowed().len(), padded_len + 16);
+            assert_eq!(
+                &sealed.decrypt_padded(8).unwrap().expose_borrowed()[..],
+                plaintext
+            );
+        });
+
+    assert_eq!(
+        &sealed_unpadded(&[7, 1])
+            .decrypt_padded(2)
+            .unwrap()
+            .expose_borrowed()[..],
+        &[7]
+    );
+}
+
+#[test]
+fn block_size_must_be_between_1_and_255() {
+    let sealed = sealed_unpadded(&[1; 4]);
+
+    [0, 256].into_iter().for_each(|block| {
+        assert_eq!(
+            sealed.decrypt_padded(block).err(),
+            Some(MemSecurityErr::InvalidPaddingBlock { block })
+        );
+        assert_eq!(
+            EncryptedMem::new()
+                .encrypt_padded(&ZeroizeBytes::new_with_data(b"secret"), block)
+                .err(),
+            Some(MemSecurityErr::InvalidPaddingBlock { block })
+        );
+    });
+}
+
+#[test]
+fn malformed_padding_is_rejected() {
+    [
+        // A pad byte of zero
+        (&[1, 2, 3, 0][..], 4),
+        // A pad byte larger than the block
+        (&[5, 5, 5, 5][..], 4),
+        // Padding bytes that do not all match the pad byte
+        (&[1, 3, 9, 3][..], 4),
+        (&[9, 9, 4, 4, 1, 4][..], 6),
+        // A plaintext that is not a multiple of the block or is empty
+        (&[1, 1, 1, 1, 1][..], 4),
+        (&[][..], 4),
+    ]
+    .into_iter()
+    .for_each(|(plaintext, block)| {
+        assert_eq!(
+            sealed_unpadded(plaintext).decrypt_padded(block).err(),
+            Some(MemSecurityErr::InvalidPadding),
+            "{plaintext:?}"
+        );
+    });
+}
+
+#[test]
+fn padding_is_checked_with_the_block_size_it_was_sealed_with() {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt_padded(&ZeroizeBytes::new_with_data(b"hello"), 8)
+        .unwrap();
+
+    assert_eq!(
+        sealed.decrypt_padded(16).err(),
+        Some(MemSecurityErr::InvalidPadding)
+    );
+}
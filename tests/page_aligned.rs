@@ -0,0 +1,43 @@
+//! A `PageAlignedZeroizeArray` fills whole pages on its own so locking it pins no adjacent data.
+
+use memsecurity::{PageAlignedZeroizeArray, PAGE_ALIGNMENT};
+
+#[test]
+fn page_aligned_array_fills_exactly_one_page() {
+    assert_eq!(core::mem::size_of::<PageAlignedZeroizeArray<4096>>(), 4096);
+    assert_eq!(PageAlignedZeroizeArray::<32>::page_span(), PAGE_ALIGNMENT);
+    assert_eq!(
+        PageAlignedZeroizeArray::<4097>::page_span(),
+        2 * PAGE_ALIGNMENT
+    );
+}
+
+#[test]
+fn page_aligned_array_is_allocated_on_a_page_boundary() {
+    let zeroed = PageAlignedZeroizeArray::<4096>::new_zeroed();
+    assert_eq!(
+        zeroed.expose_borrowed().as_ptr() as usize % PAGE_ALIGNMENT,
+        0
+    );
+    assert!(zeroed.expose_borrowed().iter().all(|byte| *byte == 0));
+    assert!(!zeroed.is_locked());
+
+    let boxed = PageAlignedZeroizeArray::new_boxed([7u8; 32]);
+    assert_eq!(
+        boxed.expose_borrowed().as_ptr() as usize % PAGE_ALIGNMENT,
+        0
+    );
+    assert_eq!(boxed.expose_borrowed(), &[7u8; 32]);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn page_aligned_array_locks_its_own_page() {
+    let mut array = PageAlignedZeroizeArray::new_boxed([7u8; 4096]);
+
+    if array.mlock() {
+        assert!(array.is_locked());
+        assert!(array.munlock());
+    }
+    assert!(!array.is_locked());
+}
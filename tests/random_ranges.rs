@@ -0,0 +1,165 @@
+//! `MinMaxNum::sample_uniform` and the bounded draws of `CsprngArraySimple` must stay inside the requested
+//! range, cover the full range of every integer type and reject empty or non-finite ranges.
+
+use memsecurity::{
+    rand_chacha::ChaCha20Rng,
+    rand_core::{RngCore, SeedableRng},
+    CsprngArraySimple, MemSecurityErr, MinMaxNum,
+};
+
+fn rng() -> ChaCha20Rng {
+    ChaCha20Rng::seed_from_u64(0x4848)
+}
+
+/// Yields each of the queued `u128` values in order, to drive the rejection loop deterministically
+struct QueuedRng(Vec<u128>);
+
+impl RngCore for QueuedRng {
+    fn next_u32(&mut self) -> u32 {
+        unreachable!()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unreachable!()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.copy_from_slice(&self.0.remove(0).to_le_bytes());
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), memsecurity::rand_core::Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn full_range_i8_covers_every_value() {
+    let mut rng = rng();
+    let mut seen = [false; 256];
+
+    (0..10_000).for_each(|_| {
+        let value = i8::sample_uniform(&mut rng, i8::MIN_VALUE, i8::MAX_VALUE).unwrap();
+        seen[(value as u8) as usize] = true;
+    });
+
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+fn full_range_i128_and_u128_draw_both_halves() {
+    let mut rng = rng();
+
+    let (negative, positive) = (0..256)
+        .map(|_| i128::sample_uniform(&mut rng, i128::MIN_VALUE, i128::MAX_VALUE).unwrap())
+        .fold((false, false), |(negative, positive), value| {
+            (negative || value < 0, positive || value > 0)
+        });
+    assert!(negative && positive);
+
+    let mut queued = QueuedRng(vec![u128::MAX]);
+    assert_eq!(
+        u128::sample_uniform(&mut queued, u128::MIN, u128::MAX),
+        Some(u128::MAX)
+    );
+}
+
+#[test]
+fn single_value_ranges_return_the_bound() {
+    let mut rng = rng();
+
+    assert_eq!(u8::sample_uniform(&mut rng, 7, 7), Some(7));
+    assert_eq!(i128::sample_uniform(&mut rng, -3, -3), Some(-3));
+    assert_eq!(
+        u64::sample_uniform(&mut rng, u64::MAX, u64::MAX),
+        Some(u64::MAX)
+    );
+    // Float ranges are half open so a single value range is empty
+    assert_eq!(f64::sample_uniform(&mut rng, 1.5, 1.5), None);
+}
+
+#[test]
+fn empty_and_non_finite_ranges_are_rejected() {
+    let mut rng = rng();
+
+    assert_eq!(u16::sample_uniform(&mut rng, 20, 10), None);
+    assert_eq!(i8::sample_uniform(&mut rng, 0, -1), None);
+    assert_eq!(f32::sample_uniform(&mut rng, 1.0, -1.0), None);
+
+    [
+        (f64::NAN, 1.0),
+        (0.0, f64::NAN),
+        (f64::NEG_INFINITY, 1.0),
+        (0.0, f64::INFINITY),
+    ]
+    .into_iter()
+    .for_each(|(low, high)| assert_eq!(f64::sample_uniform(&mut rng, low, high), None));
+}
+
+#[test]
+fn seeded_draws_stay_in_range() {
+    let mut rng = rng();
+    let mut seen = [false; 11];
+
+    (0..2_000).for_each(|_| {
+        let value = i16::sample_uniform(&mut rng, -5, 5).unwrap();
+        assert!((-5..=5).contains(&value));
+        seen[(value + 5) as usize] = true;
+
+        let value = f64::sample_uniform(&mut rng, -1.0, 1.0).unwrap();
+        assert!((-1.0..1.0).contains(&value));
+
+        let value = f32::sample_uniform(&mut rng, f32::MIN_VALUE, f32::MAX_VALUE).unwrap();
+        assert!(value.is_finite());
+    });
+
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+fn draws_in_the_incomplete_last_multiple_are_rejected() {
+    // `2^128 - 1` is a multiple of 3 so only `u128::MAX` falls outside the last complete multiple
+    let mut queued = QueuedRng(vec![u128::MAX, 5]);
+
+    assert_eq!(u8::sample_uniform(&mut queued, 10, 12), Some(12));
+    assert!(queued.0.is_empty());
+}
+
+#[test]
+fn gen_range_stays_in_range() {
+    assert!((1..=6).contains(&CsprngArraySimple::gen_range(1u8, 6u8)));
+}
+
+#[test]
+fn gen_in_range_rejects_empty_and_non_finite_ranges() {
+    assert_eq!(
+        CsprngArraySimple::gen_in_range(20u16, 10u16),
+        Err(MemSecurityErr::InvalidRange)
+    );
+    assert_eq!(
+        CsprngArraySimple::gen_in_range(1.0f32, -1.0f32),
+        Err(MemSecurityErr::InvalidRange)
+    );
+    assert_eq!(
+        CsprngArraySimple::gen_in_range(f64::NAN, 1.0),
+        Err(MemSecurityErr::InvalidRange)
+    );
+    assert_eq!(
+        CsprngArraySimple::gen_in_range(0.0, f64::INFINITY),
+        Err(MemSecurityErr::InvalidRange)
+    );
+}
+
+#[test]
+fn gen_in_range_and_gen_bounded_stay_in_range() {
+    assert_eq!(
+        CsprngArraySimple::gen_in_range(u64::MAX, u64::MAX),
+        Ok(u64::MAX)
+    );
+    (0..256).for_each(|_| {
+        assert!((10..=20).contains(&CsprngArraySimple::gen_in_range(10u16, 20u16).unwrap()));
+        assert!((-1.0..1.0).contains(&CsprngArraySimple::gen_in_range(-1.0f64, 1.0).unwrap()));
+        assert!(CsprngArraySimple::gen_bounded::<f64>().is_finite());
+    });
+}
@@ -0,0 +1,43 @@
+//! A `ScopedVault` seals values under its own sealing key which neither the process-wide sealing key
+//! nor another vault can open.
+
+use memsecurity::{EncryptedMem, MemSecurityErr, ScopedVault, ZeroizeBytes};
+
+#[test]
+fn thread_vault_round_trips_on_a_worker_thread() {
+    std::thread::spawn(|| {
+        ScopedVault::with_thread_vault(|vault| {
+            let mut sealed = EncryptedMem::with_context("worker");
+            vault
+                .encrypt(&mut sealed, &ZeroizeBytes::new_with_data(b"worker secret"))
+                .unwrap();
+
+            assert_eq!(
+                &vault.decrypt(&sealed).unwrap().expose_borrowed()[..],
+                b"worker secret"
+            );
+            assert_eq!(
+                sealed.decrypt().err(),
+                Some(MemSecurityErr::AuthenticationFailed)
+            );
+        })
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn vaults_cannot_open_each_other() {
+    let first = ScopedVault::new();
+    let second = ScopedVault::new();
+
+    let mut sealed = EncryptedMem::new();
+    first
+        .encrypt(&mut sealed, &ZeroizeBytes::new_with_data(b"first secret"))
+        .unwrap();
+
+    assert_eq!(
+        second.decrypt(&sealed).err(),
+        Some(MemSecurityErr::AuthenticationFailed)
+    );
+}
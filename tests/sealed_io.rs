@@ -0,0 +1,166 @@
+//! `SealedWriter` and `SealedReader` bind every frame to its stream, its index and whether it is the
+//! final frame, so tampered, reordered, dropped or truncated frames are rejected.
+
+use memsecurity::{SealedReader, SealedWriter, SEALED_FRAME_HEADER_LEN, SEALED_STREAM_ID_LEN};
+use std::io::{ErrorKind, Read, Write};
+
+const CHUNK_SIZE: usize = 4;
+const PLAINTEXT: &[u8] = b"0123456789";
+/// `PLAINTEXT` is sealed as two full frames followed by a final frame of two bytes
+const FULL_FRAME_LEN: usize = SEALED_FRAME_HEADER_LEN + CHUNK_SIZE + 16;
+
+fn seal(plaintext: &[u8]) -> Vec<u8> {
+    let mut sealed = Vec::new();
+    let mut writer = SealedWriter::with_chunk_size(&mut sealed, CHUNK_SIZE);
+    writer.write_all(plaintext).unwrap();
+    writer.finish().unwrap();
+
+    sealed
+}
+
+fn open(sealed: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut reader = SealedReader::with_chunk_size(sealed, CHUNK_SIZE);
+    let mut plaintext = Vec::new();
+    reader.read_to_end(&mut plaintext)?;
+
+    Ok(plaintext)
+}
+
+fn frame(index: usize) -> std::ops::Range<usize> {
+    let start = SEALED_STREAM_ID_LEN + index * FULL_FRAME_LEN;
+
+    start..start + FULL_FRAME_LEN
+}
+
+#[test]
+fn sealed_stream_round_trips() {
+    let sealed = seal(PLAINTEXT);
+    assert_eq!(
+        sealed.len(),
+        SEALED_STREAM_ID_LEN + 2 * FULL_FRAME_LEN + SEALED_FRAME_HEADER_LEN + 2 + 16
+    );
+    assert_eq!(open(&sealed).unwrap(), PLAINTEXT);
+
+    assert_eq!(open(&seal(b"")).unwrap(), b"");
+}
+
+#[test]
+fn flushed_frames_round_trip() {
+    let mut sealed = Vec::new();
+    let mut writer = SealedWriter::with_chunk_size(&mut sealed, CHUNK_SIZE);
+    writer.write_all(b"ab").unwrap();
+    writer.flush().unwrap();
+    writer.write_all(b"cdefg").unwrap();
+    writer.finish().unwrap();
+
+    assert_eq!(open(&sealed).unwrap(), b"abcdefg");
+}
+
+#[test]
+fn unfinished_stream_is_truncated() {
+    let mut sealed = Vec::new();
+    {
+        let mut writer = SealedWriter::with_chunk_size(&mut sealed, CHUNK_SIZE);
+        writer.write_all(PLAINTEXT).unwrap();
+        writer.flush().unwrap();
+    }
+
+    assert_eq!(open(&sealed).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(open(&[]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn short_reads_are_truncated() {
+    let sealed = seal(PLAINTEXT);
+
+    // Ending on a frame boundary before the final frame
+    let boundary = frame(1).end;
+    assert_eq!(
+        open(&sealed[..boundary]).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+
+    // Ending inside a header and inside a ciphertext
+    for end in [
+        SEALED_STREAM_ID_LEN - 1,
+        SEALED_STREAM_ID_LEN + 3,
+        sealed.len() - 1,
+    ] {
+        assert_eq!(
+            open(&sealed[..end]).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+}
+
+#[test]
+fn tampered_frames_are_rejected() {
+    let sealed = seal(PLAINTEXT);
+
+    let mut tampered = sealed.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 1;
+    assert_eq!(open(&tampered).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    // Flipping the final flag of the first frame
+    let mut tampered = sealed.clone();
+    tampered[SEALED_STREAM_ID_LEN + SEALED_FRAME_HEADER_LEN - 1] = 1;
+    assert_eq!(open(&tampered).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    // A different stream identifier
+    let mut tampered = sealed;
+    tampered[0] ^= 1;
+    assert_eq!(open(&tampered).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn reordered_dropped_and_duplicated_frames_are_rejected() {
+    let sealed = seal(PLAINTEXT);
+    let (first, second) = (frame(0), frame(1));
+    let header = &sealed[..SEALED_STREAM_ID_LEN];
+    let tail = &sealed[second.end..];
+
+    let reordered = [
+        header,
+        &sealed[second.clone()],
+        &sealed[first.clone()],
+        tail,
+    ]
+    .concat();
+    let dropped = [header, &sealed[second.clone()], tail].concat();
+    let duplicated = [header, &sealed[first.clone()], &sealed[first], tail].concat();
+
+    for stream in [reordered, dropped, duplicated] {
+        assert_eq!(open(&stream).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    // Frames from another stream of the same process
+    let other = seal(PLAINTEXT);
+    let spliced = [header, &other[SEALED_STREAM_ID_LEN..]].concat();
+    assert_eq!(open(&spliced).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn data_after_the_final_frame_is_rejected() {
+    let mut sealed = seal(PLAINTEXT);
+    sealed.push(0);
+
+    assert_eq!(open(&sealed).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn oversized_frames_are_rejected_before_reading() {
+    let mut sealed = seal(PLAINTEXT);
+    let len_offset = SEALED_STREAM_ID_LEN + 16;
+    sealed[len_offset..len_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    assert_eq!(open(&sealed).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    // A writer with a larger chunk size than the reader
+    let mut large = Vec::new();
+    let mut writer = SealedWriter::with_chunk_size(&mut large, CHUNK_SIZE * 2);
+    writer.write_all(PLAINTEXT).unwrap();
+    writer.finish().unwrap();
+
+    assert_eq!(open(&large).unwrap_err().kind(), ErrorKind::InvalidData);
+}
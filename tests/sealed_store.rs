@@ -0,0 +1,28 @@
+//! `SealedStore` maps keys to sealed values which can be replaced, read back and removed.
+
+use memsecurity::SealedStore;
+
+#[test]
+fn put_get_and_remove() {
+    let mut store = SealedStore::new();
+
+    store.put("database", b"hunter2").unwrap();
+    store.put("api", b"token").unwrap();
+    store.put("database", b"correct horse").unwrap();
+
+    assert_eq!(store.len(), 2);
+    assert_eq!(
+        &store.get("database").unwrap().unwrap().expose_borrowed()[..],
+        b"correct horse"
+    );
+    assert_eq!(
+        &store.get("api").unwrap().unwrap().expose_borrowed()[..],
+        b"token"
+    );
+
+    assert!(store.remove("api"));
+    assert!(!store.remove("api"));
+    assert!(!store.contains_key("api"));
+    assert!(store.get("api").unwrap().is_none());
+    assert_eq!(store.len(), 1);
+}
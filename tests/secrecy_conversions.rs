@@ -0,0 +1,35 @@
+//! `ZeroizeBytes` moves its allocation into a `SecretBox` when it holds exactly its bytes and copies otherwise.
+
+use memsecurity::{ZeroizeArray, ZeroizeBytes};
+use secrecy::{ExposeSecret, SecretSlice};
+
+#[test]
+fn exactly_sized_bytes_are_moved_into_the_secret_box() {
+    let mut bytes = ZeroizeBytes::new_with_capacity(4);
+    bytes.extend_from_slice(&[1, 2, 3, 4]);
+    let allocation = bytes.expose_borrowed().as_ptr();
+
+    let secret = SecretSlice::<u8>::from(bytes);
+    assert_eq!(secret.expose_secret(), &[1, 2, 3, 4]);
+    assert_eq!(secret.expose_secret().as_ptr(), allocation);
+}
+
+#[test]
+fn bytes_with_spare_capacity_are_copied_into_the_secret_box() {
+    let mut bytes = ZeroizeBytes::new_with_capacity(64);
+    bytes.extend_from_slice(&[1, 2, 3, 4]);
+
+    let secret = SecretSlice::<u8>::from(bytes);
+    assert_eq!(secret.expose_secret(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn secret_box_converts_back() {
+    let secret = SecretSlice::<u8>::from(ZeroizeArray::new([5u8; 8]));
+
+    let bytes = ZeroizeBytes::from(secret);
+    assert_eq!(&bytes.expose_borrowed()[..], &[5u8; 8]);
+
+    let array = ZeroizeArray::<8>::try_from(SecretSlice::<u8>::from(bytes)).unwrap();
+    assert_eq!(array.expose_borrowed(), &[5u8; 8]);
+}
@@ -0,0 +1,53 @@
+//! Refreshing the vault re-randomizes its pages in memory without changing the sealing key, so secrets
+//! encrypted before a refresh stay decryptable and the background refresh thread stops when asked to.
+
+use memsecurity::{EncryptedMem, ZeroizeBytes};
+use std::time::{Duration, Instant};
+
+fn sealed() -> EncryptedMem {
+    let mut sealed = EncryptedMem::new();
+    sealed
+        .encrypt(&ZeroizeBytes::new_with_data(b"survives a refresh"))
+        .unwrap();
+
+    sealed
+}
+
+fn assert_decryptable(sealed: &EncryptedMem) {
+    assert_eq!(
+        &sealed.decrypt().unwrap().expose_borrowed()[..],
+        b"survives a refresh"
+    );
+}
+
+#[test]
+fn refreshing_the_vault_keeps_secrets_decryptable() {
+    let before_refresh = sealed();
+
+    (0..3).for_each(|_| EncryptedMem::refresh_vault());
+
+    assert_decryptable(&before_refresh);
+    assert_decryptable(&sealed());
+}
+
+#[test]
+fn background_refresh_keeps_secrets_decryptable() {
+    let sealed = sealed();
+
+    let refresh = EncryptedMem::start_vault_refresh(Duration::from_millis(1));
+    std::thread::sleep(Duration::from_millis(50));
+    assert_decryptable(&sealed);
+    refresh.stop();
+
+    assert_decryptable(&sealed);
+}
+
+#[test]
+fn stop_wakes_and_joins_the_thread() {
+    let refresh = EncryptedMem::start_vault_refresh(Duration::from_secs(3600));
+
+    let started = Instant::now();
+    refresh.stop();
+
+    assert!(started.elapsed() < Duration::from_secs(60));
+}
@@ -0,0 +1,158 @@
+//! A global allocator that scans every block as it is freed, so tests can check that no copy of a secret
+//! is left behind in freed heap memory. Each test uses its own marker so tests running in parallel do not
+//! observe each other's secrets.
+
+use memsecurity::{seal, unseal, EncryptedMem, SealedReader, SealedWriter, ZeroizeBytes};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io::{Read, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+const MARKERS: &[&[u8]] = &[
+    b"marker-unseal-1f0c2a9d",
+    b"marker-decrypt-b38e41c7",
+    b"marker-plaintext-eq-5d92e0f4",
+    b"marker-refresh-nonce-7a41c6e2",
+    b"marker-reseal-clone-e915b03a",
+    b"marker-decrypt-padded-04d8f7b1",
+    b"marker-sealed-reader-c27f9e15",
+];
+
+static FREED_WITH_MARKER: [AtomicBool; MARKERS.len()] =
+    [const { AtomicBool::new(false) }; MARKERS.len()];
+
+struct ScanOnFree;
+
+// SAFETY: every call is forwarded to the system allocator, `dealloc` only reads the block it is about to free
+unsafe impl GlobalAlloc for ScanOnFree {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let block = core::slice::from_raw_parts(ptr, layout.size());
+
+        MARKERS.iter().enumerate().for_each(|(index, marker)| {
+            if block.windows(marker.len()).any(|window| window == *marker) {
+                FREED_WITH_MARKER[index].store(true, Ordering::SeqCst);
+            }
+        });
+
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: ScanOnFree = ScanOnFree;
+
+/// Run `f` and return `true` if a block holding `MARKERS[index]` was freed while it ran
+fn freed_with_marker(index: usize, f: impl FnOnce()) -> bool {
+    FREED_WITH_MARKER[index].store(false, Ordering::SeqCst);
+    f();
+
+    FREED_WITH_MARKER[index].load(Ordering::SeqCst)
+}
+
+#[test]
+fn unsealed_bytes_are_wiped_when_dropped() {
+    let leaked = freed_with_marker(0, || {
+        let sealed = seal(MARKERS[0]).unwrap();
+        let plaintext = unseal(&sealed).unwrap();
+        assert_eq!(&plaintext.expose_borrowed()[..], MARKERS[0]);
+    });
+
+    assert!(!leaked);
+}
+
+#[test]
+fn decrypted_bytes_are_wiped_when_dropped() {
+    let leaked = freed_with_marker(1, || {
+        let mut sealed = EncryptedMem::new();
+        sealed
+            .encrypt(&ZeroizeBytes::new_with_data(MARKERS[1]))
+            .unwrap();
+
+        let plaintext = sealed.decrypt().unwrap();
+        assert_eq!(&plaintext.expose_borrowed()[..], MARKERS[1]);
+    });
+
+    assert!(!leaked);
+}
+
+#[test]
+fn plaintext_eq_wipes_the_decrypted_secret() {
+    let leaked = freed_with_marker(2, || {
+        let mut sealed = EncryptedMem::new();
+        sealed
+            .encrypt(&ZeroizeBytes::new_with_data(MARKERS[2]))
+            .unwrap();
+
+        assert!(sealed.plaintext_eq(MARKERS[2]).unwrap());
+        assert!(!sealed.plaintext_eq(b"not the secret").unwrap());
+    });
+
+    assert!(!leaked);
+}
+
+#[test]
+fn refresh_nonce_wipes_the_decrypted_secret() {
+    let leaked = freed_with_marker(3, || {
+        let mut sealed = EncryptedMem::new();
+        sealed
+            .encrypt(&ZeroizeBytes::new_with_data(MARKERS[3]))
+            .unwrap();
+
+        sealed.refresh_nonce().unwrap();
+        assert!(sealed.plaintext_eq(MARKERS[3]).unwrap());
+    });
+
+    assert!(!leaked);
+}
+
+#[test]
+fn reseal_clone_wipes_the_decrypted_secret() {
+    let leaked = freed_with_marker(4, || {
+        let mut sealed = EncryptedMem::new();
+        sealed
+            .encrypt(&ZeroizeBytes::new_with_data(MARKERS[4]))
+            .unwrap();
+
+        let copy = sealed.reseal_clone().unwrap();
+        assert!(copy.plaintext_eq(MARKERS[4]).unwrap());
+    });
+
+    assert!(!leaked);
+}
+
+#[test]
+fn decrypt_padded_wipes_the_padded_plaintext() {
+    let leaked = freed_with_marker(5, || {
+        let mut sealed = EncryptedMem::new();
+        sealed
+            .encrypt_padded(&ZeroizeBytes::new_with_data(MARKERS[5]), 64)
+            .unwrap();
+
+        let plaintext = sealed.decrypt_padded(64).unwrap();
+        assert_eq!(&plaintext.expose_borrowed()[..], MARKERS[5]);
+    });
+
+    assert!(!leaked);
+}
+
+#[test]
+fn sealed_reader_wipes_a_partially_read_frame() {
+    let leaked = freed_with_marker(6, || {
+        let mut sealed = Vec::new();
+        let mut writer = SealedWriter::with_chunk_size(&mut sealed, 64);
+        writer.write_all(MARKERS[6]).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = SealedReader::with_chunk_size(&sealed[..], 64);
+        let mut prefix = [0u8; 4];
+        reader.read_exact(&mut prefix).unwrap();
+        assert_eq!(&prefix, &MARKERS[6][..4]);
+    });
+
+    assert!(!leaked);
+}
@@ -0,0 +1,53 @@
+//! `ZeroizeArray::read_full` retries interrupted reads, accumulates short reads and reports
+//! a reader that ends early as `UnexpectedEof`.
+
+use memsecurity::{MemSecurityErr, ZeroizeArray};
+use std::io::{self, ErrorKind, Read};
+
+/// Yields one byte per call and is interrupted before every byte
+struct Trickle {
+    bytes: Vec<u8>,
+    interrupt: bool,
+}
+
+impl Read for Trickle {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(ErrorKind::Interrupted.into());
+        }
+
+        if self.bytes.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = self.bytes.remove(0);
+
+        Ok(1)
+    }
+}
+
+#[test]
+fn interrupted_and_short_reads_fill_the_array() {
+    let mut reader = Trickle {
+        bytes: vec![1, 2, 3, 4, 5],
+        interrupt: false,
+    };
+
+    let array = ZeroizeArray::<4>::read_full(&mut reader).unwrap();
+
+    assert_eq!(array.expose_borrowed(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn early_end_is_unexpected_eof() {
+    let mut reader = Trickle {
+        bytes: vec![1, 2],
+        interrupt: false,
+    };
+
+    assert_eq!(
+        ZeroizeArray::<4>::read_full(&mut reader).err(),
+        Some(MemSecurityErr::Io(ErrorKind::UnexpectedEof))
+    );
+}
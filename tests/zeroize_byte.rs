@@ -0,0 +1,21 @@
+//! The constant time comparisons of `ZeroizeByte` must agree with comparing the plain bytes.
+
+use memsecurity::ZeroizeByte;
+
+#[test]
+fn ct_eq_u8_matches_the_byte_value() {
+    (0..=u8::MAX).for_each(|value| {
+        let byte = ZeroizeByte::from(value);
+
+        assert!(byte.ct_eq_u8(value));
+        assert!(!byte.ct_eq_u8(value.wrapping_add(1)));
+        assert!(!byte.ct_eq_u8(value ^ 0x80));
+    });
+}
+
+#[test]
+fn equality_matches_the_byte_value() {
+    assert_eq!(ZeroizeByte::new(7), ZeroizeByte::from(7));
+    assert_ne!(ZeroizeByte::new(7), ZeroizeByte::new(8));
+    assert_eq!(ZeroizeByte::new_zeroed(), ZeroizeByte::new(0));
+}
@@ -0,0 +1,37 @@
+//! `ZeroizeBytes::zeroize_prefix` and `ZeroizeBytes::zeroize_range` wipe part of the buffer in place
+//! and leave its length and the remaining bytes untouched.
+
+use memsecurity::{MemSecurityErr, ZeroizeBytes};
+
+#[test]
+fn zeroize_prefix_wipes_the_header_only() {
+    let mut bytes = ZeroizeBytes::new_with_data(b"HEADbody");
+
+    bytes.zeroize_prefix(4).unwrap();
+
+    assert_eq!(&bytes.expose_borrowed()[..], b"\0\0\0\0body");
+}
+
+#[test]
+fn zeroize_range_wipes_the_middle_only() {
+    let mut bytes = ZeroizeBytes::new_with_data(b"abcdefgh");
+
+    bytes.zeroize_range(2, 5).unwrap();
+
+    assert_eq!(&bytes.expose_borrowed()[..], b"ab\0\0\0fgh");
+}
+
+#[test]
+fn out_of_bounds_ranges_are_rejected() {
+    let mut bytes = ZeroizeBytes::new_with_data(b"abcd");
+
+    assert_eq!(
+        bytes.zeroize_prefix(5).err(),
+        Some(MemSecurityErr::IndexOutOfBounds { index: 5, len: 4 })
+    );
+    assert_eq!(
+        bytes.zeroize_range(3, 2).err(),
+        Some(MemSecurityErr::IndexOutOfBounds { index: 3, len: 2 })
+    );
+    assert_eq!(&bytes.expose_borrowed()[..], b"abcd");
+}